@@ -29,6 +29,7 @@ pub struct ChaoticMapResult {
     min_lyapunov: f64,
     fractal_dimension: f64,
     is_cubic: bool,
+    seed: u64,
 }
 
 #[wasm_bindgen]
@@ -67,6 +68,164 @@ impl ChaoticMapResult {
     pub fn is_cubic(&self) -> bool {
         self.is_cubic
     }
+
+    /// Seed that produced this map via a seeded search, or 0 when the search was unseeded.
+    /// Feeding it back to [`FractalGenerator::find_random_chaos_seeded`] reproduces the map
+    /// byte-for-byte, so a discovery can be shared as a single integer.
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// Tight, rotated bounding box of a point cloud computed via principal component analysis.
+///
+/// `angle` is the rotation (radians) of the first principal axis; `mean_x`/`mean_y` is the
+/// cloud centroid; the `*_u`/`*_v` extents are the projected min/max along the first and
+/// second principal axes (already padded by the requested margin). Pass these to
+/// [`FractalGenerator::generate_chaotic_map_batch_to_density_framed`] to re-center and
+/// axis-align an attractor so it fills the frame.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct PcaFrame {
+    mean_x: f64,
+    mean_y: f64,
+    angle: f64,
+    min_u: f64,
+    max_u: f64,
+    min_v: f64,
+    max_v: f64,
+}
+
+#[wasm_bindgen]
+impl PcaFrame {
+    #[wasm_bindgen(getter)]
+    pub fn mean_x(&self) -> f64 {
+        self.mean_x
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mean_y(&self) -> f64 {
+        self.mean_y
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_u(&self) -> f64 {
+        self.min_u
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_u(&self) -> f64 {
+        self.max_u
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_v(&self) -> f64 {
+        self.min_v
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_v(&self) -> f64 {
+        self.max_v
+    }
+}
+
+/// Compute a tight, rotated bounding box for a trajectory via principal component analysis.
+///
+/// `points` is a flat `[x0, y0, x1, y1, ...]` buffer (as produced by
+/// [`generate_trajectory_points`]). The covariance matrix's eigenvectors give the principal
+/// axes; all points are projected onto them and the per-axis extents padded by `margin`
+/// (a fraction of each axis' span). Returns a [`PcaFrame`] describing the rotated frame.
+#[wasm_bindgen]
+pub fn pca_frame_from_points(points: &[f64], margin: f64) -> PcaFrame {
+    let n = points.len() / 2;
+    if n == 0 {
+        return PcaFrame {
+            mean_x: 0.0,
+            mean_y: 0.0,
+            angle: 0.0,
+            min_u: -1.0,
+            max_u: 1.0,
+            min_v: -1.0,
+            max_v: 1.0,
+        };
+    }
+
+    let nf = n as f64;
+    let mut mean_x = 0.0;
+    let mut mean_y = 0.0;
+    for i in 0..n {
+        mean_x += points[2 * i];
+        mean_y += points[2 * i + 1];
+    }
+    mean_x /= nf;
+    mean_y /= nf;
+
+    // 2x2 covariance matrix [[sxx, sxy], [sxy, syy]].
+    let mut sxx = 0.0;
+    let mut syy = 0.0;
+    let mut sxy = 0.0;
+    for i in 0..n {
+        let dx = points[2 * i] - mean_x;
+        let dy = points[2 * i + 1] - mean_y;
+        sxx += dx * dx;
+        syy += dy * dy;
+        sxy += dx * dy;
+    }
+    sxx /= nf;
+    syy /= nf;
+    sxy /= nf;
+
+    // Eigenvalues λ = (t ± sqrt(t² - 4d)) / 2 of the symmetric covariance matrix.
+    let t = sxx + syy;
+    let d = sxx * syy - sxy * sxy;
+    let disc = (t * t - 4.0 * d).max(0.0).sqrt();
+    let lambda1 = (t + disc) / 2.0;
+
+    // Eigenvector for the larger eigenvalue, in closed form. If the off-diagonal is
+    // negligible the axes are already aligned with x/y.
+    let angle = if sxy.abs() > 1e-12 {
+        (lambda1 - sxx).atan2(sxy)
+    } else if sxx >= syy {
+        0.0
+    } else {
+        std::f64::consts::FRAC_PI_2
+    };
+
+    let (cos_a, sin_a) = (angle.cos(), angle.sin());
+
+    let mut min_u = f64::INFINITY;
+    let mut max_u = f64::NEG_INFINITY;
+    let mut min_v = f64::INFINITY;
+    let mut max_v = f64::NEG_INFINITY;
+    for i in 0..n {
+        let dx = points[2 * i] - mean_x;
+        let dy = points[2 * i + 1] - mean_y;
+        let u = dx * cos_a + dy * sin_a;
+        let v = -dx * sin_a + dy * cos_a;
+        min_u = min_u.min(u);
+        max_u = max_u.max(u);
+        min_v = min_v.min(v);
+        max_v = max_v.max(v);
+    }
+
+    let pad_u = (max_u - min_u) * margin;
+    let pad_v = (max_v - min_v) * margin;
+
+    PcaFrame {
+        mean_x,
+        mean_y,
+        angle,
+        min_u: min_u - pad_u,
+        max_u: max_u + pad_u,
+        min_v: min_v - pad_v,
+        max_v: max_v + pad_v,
+    }
 }
 
 /// Rule system for vertex selection constraints
@@ -183,6 +342,37 @@ impl Point3D {
     }
 }
 
+/// Minimal complex number used by the escape-time generator.
+#[derive(Clone, Copy, Debug)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn add(self, other: Complex) -> Complex {
+        Complex {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+
+    pub fn mul(self, other: Complex) -> Complex {
+        Complex {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
 /// Transformation parameters for fractal generation
 #[derive(Clone, Copy, Debug)]
 pub struct Transform {
@@ -266,6 +456,12 @@ pub enum ColorScheme {
 #[wasm_bindgen]
 pub struct FractalGenerator {
     rng: ThreadRng,
+    /// When set, `gradient_color` interpolates stops in HSV space (shortest-arc hue) rather
+    /// than raw RGB, and `apply_color_scheme` computes colors directly instead of via the LUT.
+    hsv_interpolation: bool,
+    /// Optional deterministic PRNG driving the chaos search. When present `get_random_args`
+    /// draws from it instead of `thread_rng`, making a discovery reproducible from its seed.
+    search_rng: std::cell::RefCell<Option<rand::rngs::StdRng>>,
 }
 
 impl Default for FractalGenerator {
@@ -347,6 +543,13 @@ static LUTS: Lazy<HashMap<ColorScheme, Lut>> = Lazy::new(|| {
     map
 });
 
+/// Registry of user-registered colormaps, each baked into a 256-entry LUT exactly like the
+/// built-in [`LUTS`] so the render hot loop stays a single table lookup. Keyed by the id handed
+/// back from [`FractalGenerator::register_custom_colormap`].
+static CUSTOM_LUTS: Lazy<std::sync::Mutex<HashMap<u32, Lut>>> =
+    Lazy::new(|| std::sync::Mutex::new(HashMap::new()));
+static NEXT_CUSTOM_ID: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
 #[inline]
 fn lut_index(normalized: f64) -> usize {
     let clamped = if normalized.is_finite() {
@@ -443,7 +646,275 @@ impl FractalGenerator {
     }
     #[wasm_bindgen(constructor)]
     pub fn new() -> FractalGenerator {
-        FractalGenerator { rng: thread_rng() }
+        FractalGenerator {
+            rng: thread_rng(),
+            hsv_interpolation: false,
+            search_rng: std::cell::RefCell::new(None),
+        }
+    }
+
+    /// Toggle HSV-space gradient interpolation for subsequent renders. With it enabled the
+    /// colormaps transition through saturated hues along the shortest arc of the color wheel
+    /// instead of desaturating through gray at near-complementary stops.
+    #[wasm_bindgen]
+    pub fn set_hsv_interpolation(&mut self, enabled: bool) {
+        self.hsv_interpolation = enabled;
+    }
+
+    /// Register a custom colormap from a flat list of `(position, r, g, b)` stops and return an
+    /// id usable with [`Self::density_grid_to_rgba_custom`].
+    ///
+    /// Positions lie in `[0,1]` and need not be evenly spaced; the stops are sorted by position
+    /// and baked into a 256-entry LUT through [`Self::gradient_color_stops`], exactly like the
+    /// built-in schemes, so rendering with a custom map is as cheap as a built-in. This lets
+    /// users import external palettes (matplotlib, cmocean, …) instead of the baked catalog.
+    #[wasm_bindgen]
+    pub fn register_custom_colormap(&self, stops: Vec<f64>) -> u32 {
+        let mut parsed: Vec<(f64, (u8, u8, u8))> = stops
+            .chunks_exact(4)
+            .map(|c| {
+                (
+                    c[0].clamp(0.0, 1.0),
+                    (
+                        c[1].round().clamp(0.0, 255.0) as u8,
+                        c[2].round().clamp(0.0, 255.0) as u8,
+                        c[3].round().clamp(0.0, 255.0) as u8,
+                    ),
+                )
+            })
+            .collect();
+        if parsed.is_empty() {
+            parsed.push((0.0, (0, 0, 0)));
+        }
+        parsed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let positions: Vec<f64> = parsed.iter().map(|p| p.0).collect();
+        let colors: Vec<(u8, u8, u8)> = parsed.iter().map(|p| p.1).collect();
+
+        let mut lut: Lut = Vec::with_capacity(256);
+        for i in 0..256 {
+            let t = i as f64 / 255.0;
+            lut.push(self.gradient_color_stops(t, &positions, &colors));
+        }
+
+        let id = NEXT_CUSTOM_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if let Ok(mut reg) = CUSTOM_LUTS.lock() {
+            reg.insert(id, lut);
+        }
+        id
+    }
+
+    /// Look up a registered custom colormap, falling back to black if the id is unknown.
+    fn apply_custom_colormap(&self, normalized: f64, custom_id: u32) -> (u8, u8, u8) {
+        let idx = lut_index(normalized);
+        CUSTOM_LUTS
+            .lock()
+            .ok()
+            .and_then(|reg| reg.get(&custom_id).and_then(|lut| lut.get(idx).copied()))
+            .unwrap_or((0, 0, 0))
+    }
+
+    /// Density-to-RGBA mapping using a registered custom colormap. `scale_mode` 0–5 match
+    /// [`Self::density_grid_to_rgba_scaled`]; mode 6 (histogram equalization) is not
+    /// supported here and maps like the default soft-log curve.
+    #[wasm_bindgen]
+    pub fn density_grid_to_rgba_custom(
+        &self,
+        density: &[u32],
+        width: usize,
+        height: usize,
+        custom_id: u32,
+        scale_mode: u32,
+    ) -> wasm_bindgen::Clamped<Vec<u8>> {
+        if density.len() != width * height {
+            return wasm_bindgen::Clamped(vec![0; width * height * 4]);
+        }
+        let max_density_val = *density.iter().max().unwrap_or(&1) as f64;
+        let mut rgba = vec![0u8; width * height * 4];
+        if max_density_val <= 0.0 {
+            return wasm_bindgen::Clamped(rgba);
+        }
+        for (i, &dv) in density.iter().enumerate() {
+            let d = dv as f64;
+            let linear_norm = d / max_density_val;
+            let mapped = match scale_mode {
+                1 => {
+                    if d > 0.0 {
+                        d.ln_1p() / max_density_val.ln_1p()
+                    } else {
+                        0.0
+                    }
+                }
+                2 => linear_norm,
+                3 | 4 => linear_norm.sqrt(),
+                5 => linear_norm.powf(0.25),
+                _ => {
+                    if linear_norm > 0.0 {
+                        (linear_norm * 10.0).ln_1p() / 10.0_f64.ln_1p()
+                    } else {
+                        0.0
+                    }
+                }
+            };
+            let (r, g, b) = self.apply_custom_colormap(mapped, custom_id);
+            let base = i * 4;
+            rgba[base] = r;
+            rgba[base + 1] = g;
+            rgba[base + 2] = b;
+            rgba[base + 3] = 255;
+        }
+        wasm_bindgen::Clamped(rgba)
+    }
+
+    /// Iterate a 2D map into an auto-framed density grid.
+    ///
+    /// Discards a warm-up transient, then runs two passes: the first tracks the visited
+    /// points' `min/max` (with a small `margin` fraction of padding) so arbitrary coefficients
+    /// still render centered, and the second re-iterates from the same seed to bin hits into a
+    /// `width × height` `u32` grid. The grid feeds the existing log-density color pipeline
+    /// (`density_grid_to_rgba_log_soft`). `step` advances one orbit iteration.
+    fn accumulate_attractor<F>(
+        &self,
+        step: F,
+        n_points: usize,
+        width: usize,
+        height: usize,
+        margin: f64,
+    ) -> Vec<u32>
+    where
+        F: Fn(f64, f64) -> (f64, f64),
+    {
+        const WARMUP: usize = 1000;
+        let mut density = vec![0u32; width * height];
+        if width == 0 || height == 0 || n_points == 0 {
+            return density;
+        }
+
+        // Warm-up to land on the attractor.
+        let (mut x, mut y) = (0.05, 0.05);
+        for _ in 0..WARMUP {
+            let (nx, ny) = step(x, y);
+            x = nx;
+            y = ny;
+        }
+        let (seed_x, seed_y) = (x, y);
+
+        // Pass 1: track bounds over the recorded points.
+        let mut min_x = x;
+        let mut max_x = x;
+        let mut min_y = y;
+        let mut max_y = y;
+        for _ in 0..n_points {
+            let (nx, ny) = step(x, y);
+            x = nx;
+            y = ny;
+            if x.is_finite() && y.is_finite() {
+                min_x = min_x.min(x);
+                max_x = max_x.max(x);
+                min_y = min_y.min(y);
+                max_y = max_y.max(y);
+            }
+        }
+        let pad_x = (max_x - min_x) * margin;
+        let pad_y = (max_y - min_y) * margin;
+        min_x -= pad_x;
+        max_x += pad_x;
+        min_y -= pad_y;
+        max_y += pad_y;
+        let span_x = max_x - min_x;
+        let span_y = max_y - min_y;
+        if !(span_x > 0.0) || !(span_y > 0.0) {
+            return density;
+        }
+
+        // Pass 2: re-iterate from the same seed and bin into the grid.
+        let (mut x, mut y) = (seed_x, seed_y);
+        for _ in 0..n_points {
+            let (nx, ny) = step(x, y);
+            x = nx;
+            y = ny;
+            if x.is_finite() && y.is_finite() {
+                let px = ((x - min_x) / span_x * width as f64) as usize;
+                let py = ((y - min_y) / span_y * height as f64) as usize;
+                if px < width && py < height {
+                    density[py * width + px] += 1;
+                }
+            }
+        }
+        density
+    }
+
+    /// Render a polynomial strange attractor `(x', y') = (map(args_x, x, y), map(args_y, x, y))`
+    /// into an auto-framed density grid, reusing the existing quadratic/cubic maps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn strange_attractor_density(
+        &self,
+        x_params: Vec<f64>,
+        y_params: Vec<f64>,
+        is_cubic: bool,
+        n_points: usize,
+        width: usize,
+        height: usize,
+    ) -> Vec<u32> {
+        self.accumulate_attractor(
+            |x, y| {
+                if is_cubic {
+                    (map_cubic(&x_params, x, y), map_cubic(&y_params, x, y))
+                } else {
+                    (map_quadratic(&x_params, x, y), map_quadratic(&y_params, x, y))
+                }
+            },
+            n_points,
+            width,
+            height,
+            0.05,
+        )
+    }
+
+    /// Render a De Jong attractor `x' = sin(a·y) − cos(b·x)`, `y' = sin(c·x) − cos(d·y)`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn de_jong_density(
+        &self,
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        n_points: usize,
+        width: usize,
+        height: usize,
+    ) -> Vec<u32> {
+        self.accumulate_attractor(
+            |x, y| ((a * y).sin() - (b * x).cos(), (c * x).sin() - (d * y).cos()),
+            n_points,
+            width,
+            height,
+            0.05,
+        )
+    }
+
+    /// Render a Clifford attractor `x' = sin(a·y) + c·cos(a·x)`, `y' = sin(b·x) + d·cos(b·y)`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn clifford_density(
+        &self,
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        n_points: usize,
+        width: usize,
+        height: usize,
+    ) -> Vec<u32> {
+        self.accumulate_attractor(
+            |x, y| {
+                (
+                    (a * y).sin() + c * (a * x).cos(),
+                    (b * x).sin() + d * (b * y).cos(),
+                )
+            },
+            n_points,
+            width,
+            height,
+            0.05,
+        )
     }
 
     /// Generate chaos game fractal points
@@ -547,6 +1018,119 @@ impl FractalGenerator {
         result
     }
 
+    /// Generate a recurrent (Markov-chain) IFS fractal.
+    ///
+    /// Unlike [`Self::ifs_fractal`], where each transform is drawn independently, the next
+    /// transform depends on the current one: `transition_js` is a flat, row-major N×N
+    /// row-stochastic matrix and the next index is sampled from the row of the previously
+    /// chosen transform. This produces directed-graph / MRCM fractals the independent model
+    /// cannot. The matrix must have exactly `transforms.len()²` entries with every row
+    /// summing to 1; on a malformed matrix this falls back to the independent behavior.
+    pub fn ifs_fractal_recurrent(
+        &mut self,
+        start_x: f64,
+        start_y: f64,
+        iterations: usize,
+        transforms_js: &Array,
+        transition_js: &Array,
+        parse_mode: &str,
+    ) -> Vec<f64> {
+        let transforms: Vec<AffineTransform> = transforms_js
+            .iter()
+            .filter_map(|t| {
+                let arr = Array::from(&t);
+                if arr.length() >= 6 {
+                    Some(AffineTransform::new(
+                        arr.get(0).as_f64().unwrap_or(0.0),
+                        arr.get(1).as_f64().unwrap_or(0.0),
+                        arr.get(2).as_f64().unwrap_or(0.0),
+                        arr.get(3).as_f64().unwrap_or(0.0),
+                        arr.get(4).as_f64().unwrap_or(0.0),
+                        arr.get(5).as_f64().unwrap_or(0.0),
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let transition: Vec<f64> = transition_js.iter().filter_map(|p| p.as_f64()).collect();
+
+        let points = self.ifs_fractal_recurrent_internal(
+            Point2D::new(start_x, start_y),
+            iterations,
+            transforms,
+            transition,
+            parse_mode == "borke",
+        );
+
+        let mut result = Vec::with_capacity(points.len() * 2);
+        for point in points {
+            result.push(point.x);
+            result.push(point.y);
+        }
+        result
+    }
+
+    /// Render an escape-time field (Julia or Mandelbrot) on the complex plane.
+    ///
+    /// Each pixel maps to `z0` over `[min_x, max_x] × [min_y, max_y]` and iterates
+    /// `z = z² + c`. For Julia `c = (c_re, c_im)` is fixed and `z0` is the pixel; for
+    /// Mandelbrot `c` is the pixel and `z0 = 0`. Iteration stops when `|z|² > 4` or `max_iter`
+    /// is reached, storing the smooth count `n + 1 − log2(log2(|z|))`; interior points store
+    /// `max_iter`. The `Vec<f64>` shares the density-grid row-major layout so the existing JS
+    /// renderer can color it directly.
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_escape_field(
+        &self,
+        c_re: f64,
+        c_im: f64,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        max_iter: usize,
+        is_julia: bool,
+    ) -> Vec<f64> {
+        let mut field = vec![0.0f64; width * height];
+        let fixed_c = Complex::new(c_re, c_im);
+
+        for py in 0..height {
+            let y = min_y + (py as f64 + 0.5) / height as f64 * (max_y - min_y);
+            for px in 0..width {
+                let x = min_x + (px as f64 + 0.5) / width as f64 * (max_x - min_x);
+
+                let pixel = Complex::new(x, y);
+                let (mut z, c) = if is_julia {
+                    (pixel, fixed_c)
+                } else {
+                    (Complex::new(0.0, 0.0), pixel)
+                };
+
+                let mut n = 0usize;
+                while n < max_iter && z.norm_sqr() <= 4.0 {
+                    z = z.mul(z).add(c);
+                    n += 1;
+                }
+
+                let value = if n >= max_iter {
+                    max_iter as f64
+                } else {
+                    // Smooth (fractional) iteration count for banding-free gradients.
+                    let log_zn = z.norm_sqr().sqrt().ln();
+                    let smooth = n as f64 + 1.0 - (log_zn / 2.0_f64.ln()).ln() / 2.0_f64.ln();
+                    smooth.max(0.0)
+                };
+
+                field[py * width + px] = value;
+            }
+        }
+
+        field
+    }
+
     /// Generate Mandelbrot set
     #[allow(clippy::too_many_arguments)]
     pub fn mandelbrot_set(
@@ -687,17 +1271,679 @@ impl FractalGenerator {
         rgba
     }
 
-    /// Generate density grid from points with explicit bounds
-    #[wasm_bindgen]
+    /// Render an escape-time field straight into RGBA through the colormap/LUT pipeline.
+    ///
+    /// Each pixel maps to a complex point; the orbit iterates `z ← z^power + c` (arbitrary real
+    /// `power` evaluated in polar form, `z^p = r^p·(cos pθ, sin pθ)`) until `|z| > radius` or
+    /// `max_iterations`. The escape is smoothed via the fractional iteration count
+    /// `ν = (n + 1 − ln(ln|z|)/ln power) / max_iterations`, clamped to `[0,1]`, so it feeds the
+    /// existing [`Self::apply_color_scheme`] directly; never-escaping interior points map to 0.
+    /// `juliaset_interpolation ∈ [0,1]` morphs between families: the seed `c` blends from the
+    /// per-pixel value (Mandelbrot, `t = 0`) toward the fixed `(c_real, c_imag)` (Julia, `t = 1`)
+    /// while `z₀` blends from 0 toward the pixel, giving a smooth sweep between the two.
     #[allow(clippy::too_many_arguments)]
-    pub fn points_to_density_grid_with_bounds(
+    pub fn escape_time_rgba(
         &self,
-        points: &[f64],
         width: usize,
         height: usize,
-        min_x: f64,
-        max_x: f64,
-        min_y: f64,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        power: f64,
+        radius: f64,
+        max_iterations: usize,
+        c_real: f64,
+        c_imag: f64,
+        juliaset_interpolation: f64,
+        color_scheme: ColorScheme,
+    ) -> wasm_bindgen::Clamped<Vec<u8>> {
+        let mut rgba = vec![0u8; width * height * 4];
+        let radius = if radius > 1.0 { radius } else { 2.0 };
+        let r2 = radius * radius;
+        let ln_power = power.max(1.000_001).ln();
+        let t = juliaset_interpolation.clamp(0.0, 1.0);
+
+        for py in 0..height {
+            for px in 0..width {
+                let fx = x_min + (px as f64 / width as f64) * (x_max - x_min);
+                let fy = y_min + (py as f64 / height as f64) * (y_max - y_min);
+
+                // Blend the seed between Mandelbrot (c = pixel, z0 = 0) and Julia (c = const,
+                // z0 = pixel).
+                let cx = (1.0 - t) * fx + t * c_real;
+                let cy = (1.0 - t) * fy + t * c_imag;
+                let mut zx = t * fx;
+                let mut zy = t * fy;
+
+                let mut iteration = 0usize;
+                let mut mag2 = zx * zx + zy * zy;
+                while mag2 <= r2 && iteration < max_iterations {
+                    // z ← z^power + c in polar form (power == 2 matches the plain quadratic).
+                    let r = mag2.sqrt();
+                    let theta = zy.atan2(zx);
+                    let rp = r.powf(power);
+                    let nt = theta * power;
+                    zx = rp * nt.cos() + cx;
+                    zy = rp * nt.sin() + cy;
+                    mag2 = zx * zx + zy * zy;
+                    iteration += 1;
+                }
+
+                let normalized = if iteration >= max_iterations {
+                    0.0 // interior
+                } else {
+                    let log_zn = mag2.sqrt().ln();
+                    let nu = iteration as f64 + 1.0 - (log_zn.ln() / ln_power);
+                    (nu / max_iterations as f64).clamp(0.0, 1.0)
+                };
+
+                let (r, g, b) = self.apply_color_scheme(normalized, color_scheme);
+                let base = (py * width + px) * 4;
+                rgba[base] = r;
+                rgba[base + 1] = g;
+                rgba[base + 2] = b;
+                rgba[base + 3] = 255;
+            }
+        }
+
+        wasm_bindgen::Clamped(rgba)
+    }
+
+    /// Apply a bloom/glow post-process to an RGBA buffer.
+    ///
+    /// Pixels whose luminance (`0.2126 r + 0.7152 g + 0.0722 b`) exceeds `threshold` form a
+    /// bright-pass buffer, which is blurred with a separable Gaussian (horizontal then vertical,
+    /// kernel radius `radius`, weights `exp(−x²/2σ²)` normalized to sum 1) and additively
+    /// composited back onto the original at `intensity`, clamping each channel to 255. Dense
+    /// attractors and Sierpinski structures gain a glowing, CRT-like halo without touching the
+    /// accumulation code.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bloom_rgba(
+        &self,
+        rgba: &[u8],
+        width: usize,
+        height: usize,
+        threshold: f64,
+        sigma: f64,
+        radius: usize,
+        intensity: f64,
+    ) -> wasm_bindgen::Clamped<Vec<u8>> {
+        let pixels = width * height;
+        if rgba.len() != pixels * 4 {
+            return wasm_bindgen::Clamped(rgba.to_vec());
+        }
+
+        // Bright-pass: keep only high-luminance pixels, one f64 plane per channel.
+        let mut bright = [
+            vec![0.0f64; pixels],
+            vec![0.0f64; pixels],
+            vec![0.0f64; pixels],
+        ];
+        for i in 0..pixels {
+            let r = rgba[i * 4] as f64;
+            let g = rgba[i * 4 + 1] as f64;
+            let b = rgba[i * 4 + 2] as f64;
+            if 0.2126 * r + 0.7152 * g + 0.0722 * b > threshold {
+                bright[0][i] = r;
+                bright[1][i] = g;
+                bright[2][i] = b;
+            }
+        }
+
+        // Normalized Gaussian kernel of the requested radius.
+        let sigma = if sigma > 0.0 { sigma } else { 1.0 };
+        let radius = radius.max(1) as isize;
+        let denom = 2.0 * sigma * sigma;
+        let mut kernel = Vec::with_capacity((2 * radius + 1) as usize);
+        let mut ksum = 0.0;
+        for i in -radius..=radius {
+            let w = (-((i * i) as f64) / denom).exp();
+            kernel.push(w);
+            ksum += w;
+        }
+        for w in kernel.iter_mut() {
+            *w /= ksum;
+        }
+
+        // Two-pass separable blur of each bright-pass plane.
+        for plane in bright.iter_mut() {
+            let mut tmp = vec![0.0f64; pixels];
+            for y in 0..height {
+                for x in 0..width {
+                    let mut acc = 0.0;
+                    for (k, &w) in kernel.iter().enumerate() {
+                        let sx = (x as isize + k as isize - radius).clamp(0, width as isize - 1);
+                        acc += w * plane[y * width + sx as usize];
+                    }
+                    tmp[y * width + x] = acc;
+                }
+            }
+            for x in 0..width {
+                for y in 0..height {
+                    let mut acc = 0.0;
+                    for (k, &w) in kernel.iter().enumerate() {
+                        let sy = (y as isize + k as isize - radius).clamp(0, height as isize - 1);
+                        acc += w * tmp[sy as usize * width + x];
+                    }
+                    plane[y * width + x] = acc;
+                }
+            }
+        }
+
+        // Additive composite back onto the original.
+        let mut out = rgba.to_vec();
+        for i in 0..pixels {
+            for ch in 0..3 {
+                let v = out[i * 4 + ch] as f64 + intensity * bright[ch][i];
+                out[i * 4 + ch] = v.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+
+        wasm_bindgen::Clamped(out)
+    }
+
+    /// Serialize a trajectory as a single resolution-independent SVG `<path>` element.
+    ///
+    /// `points` is a flat `[x0, y0, x1, y1, …]` buffer (as produced by
+    /// [`generate_trajectory_points`]); the first point becomes an `M` command and the rest
+    /// `L` commands, so the browser can offer crisp downloadable art at any zoom.
+    #[wasm_bindgen]
+    pub fn trajectory_to_svg_path(&self, points: &[f64], stroke_width: f64) -> String {
+        let mut d = String::new();
+        for (i, chunk) in points.chunks_exact(2).enumerate() {
+            let cmd = if i == 0 { 'M' } else { 'L' };
+            d.push_str(&format!("{} {:.4} {:.4} ", cmd, chunk[0], chunk[1]));
+        }
+        format!(
+            "<path d=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"{}\"/>",
+            d.trim_end(),
+            stroke_width
+        )
+    }
+
+    /// Emit PDF path operators drawing each occupied density cell as a filled circle.
+    ///
+    /// Every cell with a nonzero count becomes a disc of the given `radius`, approximated by
+    /// the standard four cubic Béziers whose off-axis control handles sit at `±k·radius` with
+    /// `k = 0.5522847498`. The returned bytes are a PDF content stream (path operators) the
+    /// browser can embed in a downloadable document for crisp vector output at any zoom.
+    #[wasm_bindgen]
+    pub fn density_to_pdf_dots(
+        &self,
+        density: &[u32],
+        width: usize,
+        height: usize,
+        radius: f64,
+    ) -> Vec<u8> {
+        const K: f64 = 0.5522847498;
+        let k = K * radius;
+        let mut stream = String::new();
+
+        if density.len() == width * height {
+            for y in 0..height {
+                for x in 0..width {
+                    if density[y * width + x] == 0 {
+                        continue;
+                    }
+                    let cx = x as f64 + 0.5;
+                    let cy = y as f64 + 0.5;
+                    let r = radius;
+                    // Four cubic Béziers tracing the circle, then fill.
+                    stream.push_str(&format!("{:.4} {:.4} m\n", cx + r, cy));
+                    stream.push_str(&format!(
+                        "{:.4} {:.4} {:.4} {:.4} {:.4} {:.4} c\n",
+                        cx + r,
+                        cy + k,
+                        cx + k,
+                        cy + r,
+                        cx,
+                        cy + r
+                    ));
+                    stream.push_str(&format!(
+                        "{:.4} {:.4} {:.4} {:.4} {:.4} {:.4} c\n",
+                        cx - k,
+                        cy + r,
+                        cx - r,
+                        cy + k,
+                        cx - r,
+                        cy
+                    ));
+                    stream.push_str(&format!(
+                        "{:.4} {:.4} {:.4} {:.4} {:.4} {:.4} c\n",
+                        cx - r,
+                        cy - k,
+                        cx - k,
+                        cy - r,
+                        cx,
+                        cy - r
+                    ));
+                    stream.push_str(&format!(
+                        "{:.4} {:.4} {:.4} {:.4} {:.4} {:.4} c\n",
+                        cx + k,
+                        cy - r,
+                        cx + r,
+                        cy - k,
+                        cx + r,
+                        cy
+                    ));
+                    stream.push_str("f\n");
+                }
+            }
+        }
+
+        stream.into_bytes()
+    }
+
+    /// Extract iso-contours from an iteration grid and serialize them as an SVG document.
+    ///
+    /// Runs marching squares over the `Vec<u32>` grids produced by [`Self::mandelbrot_set`],
+    /// [`Self::julia_set`] and [`Self::burning_ship`]: for each 2×2 cell a 4-bit case index is
+    /// formed from which corners exceed the threshold, the 16-case topology decides which edges
+    /// are crossed, and each crossing is linearly interpolated between the two corner iteration
+    /// values. Segments sharing an endpoint are chained into open/closed polylines and written
+    /// as one colored `<path>` per threshold, the stroke taken from `color_scheme` at
+    /// `threshold / max_iterations`. The output is resolution-independent (plotter/laser ready).
+    #[allow(clippy::too_many_arguments)]
+    pub fn contours_to_svg(
+        &self,
+        grid: &[u32],
+        width: usize,
+        height: usize,
+        thresholds: &[u32],
+        max_iterations: usize,
+        color_scheme: ColorScheme,
+    ) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {} {}\">\n",
+            width, height, width, height
+        );
+
+        if grid.len() == width * height && width >= 2 && height >= 2 {
+            for &threshold in thresholds {
+                let segments = self.marching_squares(grid, width, height, threshold as f64);
+                let polylines = chain_segments(&segments);
+                if polylines.is_empty() {
+                    continue;
+                }
+
+                let norm = if max_iterations > 0 {
+                    (threshold as f64 / max_iterations as f64).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let (r, g, b) = self.apply_color_scheme(norm, color_scheme);
+
+                let mut d = String::new();
+                for line in &polylines {
+                    if line.len() < 2 {
+                        continue;
+                    }
+                    d.push_str(&format!("M {:.3} {:.3}", line[0].0, line[0].1));
+                    for p in &line[1..] {
+                        d.push_str(&format!(" L {:.3} {:.3}", p.0, p.1));
+                    }
+                    // Close the path when it returns to its start (within half a pixel).
+                    let first = line[0];
+                    let last = *line.last().unwrap();
+                    if (first.0 - last.0).abs() < 0.5 && (first.1 - last.1).abs() < 0.5 {
+                        d.push_str(" Z");
+                    }
+                    d.push(' ');
+                }
+
+                svg.push_str(&format!(
+                    "  <path d=\"{}\" fill=\"none\" stroke=\"rgb({},{},{})\" \
+                     stroke-width=\"1\" stroke-linejoin=\"round\"/>\n",
+                    d.trim_end(),
+                    r,
+                    g,
+                    b
+                ));
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Marching-squares pass at a single iso-level, returning line segments in pixel
+    /// coordinates. Each crossed cell edge is linearly interpolated between its two corner
+    /// iteration counts; saddle cells (four crossings) are disambiguated by the cell's mean.
+    fn marching_squares(
+        &self,
+        grid: &[u32],
+        width: usize,
+        height: usize,
+        threshold: f64,
+    ) -> Vec<((f64, f64), (f64, f64))> {
+        let mut segments = Vec::new();
+        let at = |x: usize, y: usize| grid[y * width + x] as f64;
+
+        for cy in 0..height - 1 {
+            for cx in 0..width - 1 {
+                let tl = at(cx, cy);
+                let tr = at(cx + 1, cy);
+                let br = at(cx + 1, cy + 1);
+                let bl = at(cx, cy + 1);
+
+                let case = (tl > threshold) as u8
+                    | (((tr > threshold) as u8) << 1)
+                    | (((br > threshold) as u8) << 2)
+                    | (((bl > threshold) as u8) << 3);
+                if case == 0 || case == 15 {
+                    continue;
+                }
+
+                // Interpolated crossing points on each edge (top, right, bottom, left).
+                let lerp = |a: f64, b: f64| -> f64 {
+                    if (b - a).abs() < f64::EPSILON {
+                        0.5
+                    } else {
+                        ((threshold - a) / (b - a)).clamp(0.0, 1.0)
+                    }
+                };
+                let fx = cx as f64;
+                let fy = cy as f64;
+                let top = (fx + lerp(tl, tr), fy);
+                let right = (fx + 1.0, fy + lerp(tr, br));
+                let bottom = (fx + lerp(bl, br), fy + 1.0);
+                let left = (fx, fy + lerp(tl, bl));
+
+                match case {
+                    1 | 14 => segments.push((left, top)),
+                    2 | 13 => segments.push((top, right)),
+                    3 | 12 => segments.push((left, right)),
+                    4 | 11 => segments.push((right, bottom)),
+                    6 | 9 => segments.push((top, bottom)),
+                    7 | 8 => segments.push((left, bottom)),
+                    5 => {
+                        // Saddle: connect by comparing the cell mean to the threshold.
+                        if (tl + tr + br + bl) / 4.0 > threshold {
+                            segments.push((left, top));
+                            segments.push((right, bottom));
+                        } else {
+                            segments.push((left, bottom));
+                            segments.push((top, right));
+                        }
+                    }
+                    10 => {
+                        if (tl + tr + br + bl) / 4.0 > threshold {
+                            segments.push((top, right));
+                            segments.push((left, bottom));
+                        } else {
+                            segments.push((left, top));
+                            segments.push((right, bottom));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        segments
+    }
+
+    /// Generate a smooth Mandelbrot field: two `f64` per pixel, interleaved as
+    /// `[mu, dist]`. `mu = n + 1 - ln(ln|z|)/ln 2` is the fractional escape count (bailout
+    /// `|z|² > 2¹⁶`), and `dist = 0.5·|z|·ln|z| / |dz|` is the distance estimate carried via
+    /// `dz' = 2·z·dz + 1`. Interior pixels store `mu = max_iterations` and `dist = +inf`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mandelbrot_set_smooth(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        max_iterations: usize,
+    ) -> Vec<f64> {
+        const BAILOUT2: f64 = 65536.0;
+        let mut result = Vec::with_capacity(width * height * 2);
+
+        for py in 0..height {
+            for px in 0..width {
+                let x0 = x_min + (px as f64 / width as f64) * (x_max - x_min);
+                let y0 = y_min + (py as f64 / height as f64) * (y_max - y_min);
+
+                let mut x = 0.0;
+                let mut y = 0.0;
+                let mut dx = 0.0;
+                let mut dy = 0.0;
+                let mut iteration = 0;
+                let mut mag2 = 0.0;
+
+                while iteration < max_iterations {
+                    // dz = 2·z·dz + 1, using z before its update.
+                    let new_dx = 2.0 * (x * dx - y * dy) + 1.0;
+                    let new_dy = 2.0 * (x * dy + y * dx);
+                    dx = new_dx;
+                    dy = new_dy;
+
+                    let xtemp = x * x - y * y + x0;
+                    y = 2.0 * x * y + y0;
+                    x = xtemp;
+                    iteration += 1;
+                    mag2 = x * x + y * y;
+                    if mag2 > BAILOUT2 {
+                        break;
+                    }
+                }
+
+                if iteration >= max_iterations {
+                    result.push(max_iterations as f64);
+                    result.push(f64::INFINITY);
+                } else {
+                    let mag = mag2.sqrt();
+                    let ln_zn = mag.ln();
+                    let mu = iteration as f64 + 1.0 - (ln_zn.ln()) / std::f64::consts::LN_2;
+                    let dmag = (dx * dx + dy * dy).sqrt();
+                    let dist = if dmag > 0.0 { 0.5 * mag * ln_zn / dmag } else { 0.0 };
+                    result.push(mu);
+                    result.push(dist);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Generate a smooth Julia field (interleaved `[mu, dist]`). The derivative recurrence is
+    /// `dz' = 2·z·dz` with `dz₀ = 1`. See [`mandelbrot_set_smooth`](Self::mandelbrot_set_smooth).
+    #[allow(clippy::too_many_arguments)]
+    pub fn julia_set_smooth(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        c_real: f64,
+        c_imag: f64,
+        max_iterations: usize,
+    ) -> Vec<f64> {
+        const BAILOUT2: f64 = 65536.0;
+        let mut result = Vec::with_capacity(width * height * 2);
+
+        for py in 0..height {
+            for px in 0..width {
+                let mut x = x_min + (px as f64 / width as f64) * (x_max - x_min);
+                let mut y = y_min + (py as f64 / height as f64) * (y_max - y_min);
+                let mut dx = 1.0;
+                let mut dy = 0.0;
+                let mut iteration = 0;
+                let mut mag2 = x * x + y * y;
+
+                while iteration < max_iterations {
+                    let new_dx = 2.0 * (x * dx - y * dy);
+                    let new_dy = 2.0 * (x * dy + y * dx);
+                    dx = new_dx;
+                    dy = new_dy;
+
+                    let xtemp = x * x - y * y + c_real;
+                    y = 2.0 * x * y + c_imag;
+                    x = xtemp;
+                    iteration += 1;
+                    mag2 = x * x + y * y;
+                    if mag2 > BAILOUT2 {
+                        break;
+                    }
+                }
+
+                if iteration >= max_iterations {
+                    result.push(max_iterations as f64);
+                    result.push(f64::INFINITY);
+                } else {
+                    let mag = mag2.sqrt();
+                    let ln_zn = mag.ln();
+                    let mu = iteration as f64 + 1.0 - (ln_zn.ln()) / std::f64::consts::LN_2;
+                    let dmag = (dx * dx + dy * dy).sqrt();
+                    let dist = if dmag > 0.0 { 0.5 * mag * ln_zn / dmag } else { 0.0 };
+                    result.push(mu);
+                    result.push(dist);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Generate a smooth Burning Ship field (interleaved `[mu, dist]`). The folded iteration
+    /// has no analytic derivative, so `dist` is always `+inf` and coloring falls back to the
+    /// pure smooth count. See [`mandelbrot_set_smooth`](Self::mandelbrot_set_smooth).
+    #[allow(clippy::too_many_arguments)]
+    pub fn burning_ship_smooth(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        max_iterations: usize,
+    ) -> Vec<f64> {
+        const BAILOUT2: f64 = 65536.0;
+        let mut result = Vec::with_capacity(width * height * 2);
+
+        for py in 0..height {
+            for px in 0..width {
+                let x0 = x_min + (px as f64 / width as f64) * (x_max - x_min);
+                let y0 = y_min + (py as f64 / height as f64) * (y_max - y_min);
+
+                let mut x = 0.0;
+                let mut y = 0.0;
+                let mut iteration = 0;
+                let mut mag2 = 0.0;
+
+                while iteration < max_iterations {
+                    let xtemp = x * x - y * y + x0;
+                    y = 2.0 * x.abs() * y.abs() + y0;
+                    x = xtemp;
+                    iteration += 1;
+                    mag2 = x * x + y * y;
+                    if mag2 > BAILOUT2 {
+                        break;
+                    }
+                }
+
+                if iteration >= max_iterations {
+                    result.push(max_iterations as f64);
+                } else {
+                    let ln_zn = mag2.sqrt().ln();
+                    let mu = iteration as f64 + 1.0 - (ln_zn.ln()) / std::f64::consts::LN_2;
+                    result.push(mu);
+                }
+                result.push(f64::INFINITY);
+            }
+        }
+
+        result
+    }
+
+    /// Convert a smooth `[mu, dist]` field to RGBA with anti-aliased edges.
+    ///
+    /// `mu` feeds the colormap continuously (no banding); where the distance estimate is
+    /// finite and small relative to `pixel_spacing`, the pixel is darkened toward a fringe so
+    /// thin filaments and the set boundary get anti-aliased. Interior pixels
+    /// (`mu >= max_iterations`) render black.
+    #[allow(clippy::too_many_arguments)]
+    pub fn smooth_values_to_rgba(
+        &self,
+        values: &[f64],
+        width: usize,
+        height: usize,
+        max_iterations: usize,
+        pixel_spacing: f64,
+        color_scheme: ColorScheme,
+    ) -> Vec<u8> {
+        let mut rgba = Vec::with_capacity(width * height * 4);
+        let spacing = if pixel_spacing > 0.0 { pixel_spacing } else { 1.0 };
+
+        for pair in values.chunks_exact(2) {
+            let mu = pair[0];
+            let dist = pair[1];
+            let (r, g, b) = if mu >= max_iterations as f64 {
+                (0, 0, 0)
+            } else {
+                let normalized = (mu / max_iterations as f64).clamp(0.0, 1.0).sqrt();
+                let (cr, cg, cb) = self.apply_color_scheme(normalized, color_scheme);
+                if dist.is_finite() {
+                    // Fringe coverage: 0 on the boundary, 1 a pixel or more away.
+                    let coverage = (dist / spacing).clamp(0.0, 1.0);
+                    (
+                        (cr as f64 * coverage) as u8,
+                        (cg as f64 * coverage) as u8,
+                        (cb as f64 * coverage) as u8,
+                    )
+                } else {
+                    (cr, cg, cb)
+                }
+            };
+            rgba.push(r);
+            rgba.push(g);
+            rgba.push(b);
+            rgba.push(255);
+        }
+
+        rgba
+    }
+
+    /// Sample a Mandelbulb on a voxel grid, mesh its isosurface with marching cubes and
+    /// return a downloadable binary STL (`Vec<u8>`).
+    ///
+    /// The field iterates `z → z^power + c` in spherical coordinates over a `resolution³`
+    /// grid spanning `[min, max]³`, storing the escape iteration count; the `iso` level then
+    /// selects the boundary surface. See [`FractalGenerator::mandelbulb_point`] for the
+    /// per-voxel iteration.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mandelbulb_stl(
+        &self,
+        resolution: usize,
+        power: f64,
+        max_iterations: usize,
+        min: f64,
+        max: f64,
+        iso: f32,
+    ) -> Vec<u8> {
+        let field = self.mandelbulb_field(resolution, power, max_iterations, min, max);
+        let triangles = self.marching_cubes(&field, resolution, min, max, iso);
+        self.mesh_to_binary_stl(&triangles)
+    }
+
+    /// Generate density grid from points with explicit bounds
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn points_to_density_grid_with_bounds(
+        &self,
+        points: &[f64],
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
         max_y: f64,
     ) -> Vec<u32> {
         if points.len() % 2 != 0 {
@@ -740,6 +1986,104 @@ impl FractalGenerator {
         grid1.iter().zip(grid2.iter()).map(|(a, b)| a + b).collect()
     }
 
+    /// Separable Gaussian blur of a density grid.
+    ///
+    /// Builds a 1D kernel of radius `⌈3σ⌉` from `exp(-x²/2σ²)` normalized to sum 1 and
+    /// convolves rows then columns (O(n·k) rather than O(n·k²)). Edges are handled by
+    /// clamping the sample index. `sigma <= 0` returns the grid unchanged. The result is a
+    /// rounded `u32` buffer suitable for feeding back into the `density_grid_to_rgba*` maps.
+    #[wasm_bindgen]
+    pub fn blur_density(&self, grid: &[u32], width: usize, height: usize, sigma: f64) -> Vec<u32> {
+        if grid.len() != width * height || !(sigma > 0.0) {
+            return grid.to_vec();
+        }
+        let buf: Vec<f64> = grid.iter().map(|&d| d as f64).collect();
+        let blurred = self.separable_gaussian(&buf, width, height, sigma);
+        blurred.iter().map(|&v| v.max(0.0).round() as u32).collect()
+    }
+
+    /// Additive glow: blends a Gaussian-blurred copy back over the original at `strength`,
+    /// i.e. `orig + strength·blurred`. Produces the soft bloom characteristic of fractal-flame
+    /// renders. Returns a rounded `u32` density buffer.
+    #[wasm_bindgen]
+    pub fn glow_density(
+        &self,
+        grid: &[u32],
+        width: usize,
+        height: usize,
+        sigma: f64,
+        strength: f64,
+    ) -> Vec<u32> {
+        if grid.len() != width * height {
+            return grid.to_vec();
+        }
+        let buf: Vec<f64> = grid.iter().map(|&d| d as f64).collect();
+        let blurred = self.separable_gaussian(&buf, width, height, sigma);
+        buf.iter()
+            .zip(blurred.iter())
+            .map(|(&o, &b)| (o + strength * b).max(0.0).round() as u32)
+            .collect()
+    }
+
+    /// Generic 3×3 convolution of a density grid for sharpen/emboss/edge kernels.
+    ///
+    /// Each output pixel is `(Σ kernel·neighbourhood) / divisor + bias`, with the sample
+    /// index clamped at the borders. `kernel` must hold 9 values in row-major order; a wrong
+    /// length or mismatched grid returns the input unchanged. Negative results are clamped to 0.
+    #[wasm_bindgen]
+    pub fn convolve_density(
+        &self,
+        grid: &[u32],
+        width: usize,
+        height: usize,
+        kernel: &[f64],
+        divisor: f64,
+        bias: f64,
+    ) -> Vec<u32> {
+        if grid.len() != width * height || kernel.len() != 9 {
+            return grid.to_vec();
+        }
+        let div = if divisor != 0.0 { divisor } else { 1.0 };
+        let buf: Vec<f64> = grid.iter().map(|&d| d as f64).collect();
+        let mut out = vec![0u32; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let mut acc = 0.0;
+                for ky in 0..3 {
+                    for kx in 0..3 {
+                        let sx = (x as isize + kx as isize - 1).clamp(0, width as isize - 1) as usize;
+                        let sy = (y as isize + ky as isize - 1).clamp(0, height as isize - 1) as usize;
+                        acc += kernel[ky * 3 + kx] * buf[sy * width + sx];
+                    }
+                }
+                out[y * width + x] = (acc / div + bias).max(0.0).round() as u32;
+            }
+        }
+        out
+    }
+
+    /// Unsharp mask: `orig + amount·(orig − blurred)`, accentuating local detail. Uses the same
+    /// separable Gaussian as [`blur_density`] for the low-pass term. Returns a rounded `u32` buffer.
+    #[wasm_bindgen]
+    pub fn unsharp_density(
+        &self,
+        grid: &[u32],
+        width: usize,
+        height: usize,
+        sigma: f64,
+        amount: f64,
+    ) -> Vec<u32> {
+        if grid.len() != width * height {
+            return grid.to_vec();
+        }
+        let buf: Vec<f64> = grid.iter().map(|&d| d as f64).collect();
+        let blurred = self.separable_gaussian(&buf, width, height, sigma);
+        buf.iter()
+            .zip(blurred.iter())
+            .map(|(&o, &b)| (o + amount * (o - b)).max(0.0).round() as u32)
+            .collect()
+    }
+
     /// Converts a density grid to RGBA pixel data.
     ///
     /// This function normalizes the density values in the grid by dividing each value by the maximum density,
@@ -798,6 +2142,185 @@ impl FractalGenerator {
         wasm_bindgen::Clamped(rgba)
     }
 
+    /// Euclidean distance transform of the density grid for proximity-based shading.
+    ///
+    /// Every cell with a nonzero hit count is a seed; the result holds, per cell, the
+    /// Euclidean distance to the nearest seed, clamped to `max_distance`. This lets renderers
+    /// draw smooth glow or falloff around an attractor's filaments. Uses the exact
+    /// Felzenszwalb–Huttenlocher two-pass squared-distance transform (along rows, then
+    /// columns) for O(width·height) cost.
+    #[wasm_bindgen]
+    pub fn density_to_distance_field(
+        &self,
+        density: &[u32],
+        width: usize,
+        height: usize,
+        max_distance: f64,
+    ) -> Vec<f64> {
+        if density.len() != width * height || width == 0 || height == 0 {
+            return vec![0.0; width * height];
+        }
+
+        const INF: f64 = 1e20;
+
+        // Seed grid: 0 at occupied cells, +∞ elsewhere.
+        let mut grid = vec![INF; width * height];
+        for (i, &d) in density.iter().enumerate() {
+            if d > 0 {
+                grid[i] = 0.0;
+            }
+        }
+
+        // Pass 1: 1-D squared transform along each row.
+        for y in 0..height {
+            let row: Vec<f64> = (0..width).map(|x| grid[y * width + x]).collect();
+            let dt = Self::edt_1d(&row);
+            for x in 0..width {
+                grid[y * width + x] = dt[x];
+            }
+        }
+
+        // Pass 2: 1-D squared transform down each column over the row results.
+        for x in 0..width {
+            let col: Vec<f64> = (0..height).map(|y| grid[y * width + x]).collect();
+            let dt = Self::edt_1d(&col);
+            for y in 0..height {
+                grid[y * width + x] = dt[y];
+            }
+        }
+
+        // Take the square root and clamp to max_distance.
+        grid.iter()
+            .map(|&sq| sq.sqrt().min(max_distance))
+            .collect()
+    }
+
+    /// One-dimensional squared Euclidean distance transform (lower envelope of parabolas).
+    fn edt_1d(f: &[f64]) -> Vec<f64> {
+        let n = f.len();
+        let mut d = vec![0.0; n];
+        if n == 0 {
+            return d;
+        }
+
+        let mut v = vec![0usize; n]; // locations of parabola vertices
+        let mut z = vec![0.0f64; n + 1]; // boundaries between parabolas
+        let mut k = 0usize;
+        v[0] = 0;
+        z[0] = f64::NEG_INFINITY;
+        z[1] = f64::INFINITY;
+
+        for q in 1..n {
+            let mut s = ((f[q] + (q * q) as f64) - (f[v[k]] + (v[k] * v[k]) as f64))
+                / (2 * q - 2 * v[k]) as f64;
+            while s <= z[k] {
+                k -= 1;
+                s = ((f[q] + (q * q) as f64) - (f[v[k]] + (v[k] * v[k]) as f64))
+                    / (2 * q - 2 * v[k]) as f64;
+            }
+            k += 1;
+            v[k] = q;
+            z[k] = s;
+            z[k + 1] = f64::INFINITY;
+        }
+
+        let mut k = 0usize;
+        for q in 0..n {
+            while z[k + 1] < q as f64 {
+                k += 1;
+            }
+            let dq = q as f64 - v[k] as f64;
+            d[q] = dq * dq + f[v[k]];
+        }
+
+        d
+    }
+
+    /// Build a 3D relief mesh from the density grid, keeping only steep regions.
+    ///
+    /// The counts are normalized to a `[0, 1]` height map, per-cell steepness is the magnitude
+    /// of the central-difference gradient of that height, and only grid points whose steepness
+    /// exceeds `steepness_threshold` are triangulated. A quad is emitted (two triangles) when
+    /// all four of its corners are retained. The result is a single length-prefixed `f64`
+    /// buffer the WebGL side can split directly: `[n_vertex_floats, x, y, z, …, n_indices,
+    /// i0, i1, i2, …]`, where `(x, y)` are normalized grid coordinates and `z` the normalized
+    /// density.
+    #[wasm_bindgen]
+    pub fn density_to_mesh(
+        &self,
+        density: &[u32],
+        width: usize,
+        height: usize,
+        steepness_threshold: f64,
+    ) -> Vec<f64> {
+        if density.len() != width * height || width < 2 || height < 2 {
+            return vec![0.0, 0.0];
+        }
+
+        // Normalize counts to a height map in [0, 1].
+        let max_density = *density.iter().max().unwrap_or(&1);
+        let max_f = if max_density == 0 { 1.0 } else { max_density as f64 };
+        let h: Vec<f64> = density.iter().map(|&d| d as f64 / max_f).collect();
+
+        let at = |x: usize, y: usize| h[y * width + x];
+
+        // Per-cell steepness via central differences (clamped at borders).
+        let mut keep = vec![false; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let xm = x.saturating_sub(1);
+                let xp = (x + 1).min(width - 1);
+                let ym = y.saturating_sub(1);
+                let yp = (y + 1).min(height - 1);
+                let gx = (at(xp, y) - at(xm, y)) / 2.0;
+                let gy = (at(x, yp) - at(x, ym)) / 2.0;
+                keep[y * width + x] = (gx * gx + gy * gy).sqrt() > steepness_threshold;
+            }
+        }
+
+        // Assign a vertex index to each retained grid point.
+        let mut vertex_index = vec![u32::MAX; width * height];
+        let mut vertices: Vec<f64> = Vec::new();
+        let mut next = 0u32;
+        for y in 0..height {
+            for x in 0..width {
+                if keep[y * width + x] {
+                    vertex_index[y * width + x] = next;
+                    next += 1;
+                    vertices.push(x as f64 / (width - 1) as f64);
+                    vertices.push(y as f64 / (height - 1) as f64);
+                    vertices.push(h[y * width + x]);
+                }
+            }
+        }
+
+        // Triangulate quads whose four corners are all retained.
+        let mut indices: Vec<f64> = Vec::new();
+        for y in 0..height - 1 {
+            for x in 0..width - 1 {
+                let tl = vertex_index[y * width + x];
+                let tr = vertex_index[y * width + x + 1];
+                let bl = vertex_index[(y + 1) * width + x];
+                let br = vertex_index[(y + 1) * width + x + 1];
+                if tl != u32::MAX && tr != u32::MAX && bl != u32::MAX && br != u32::MAX {
+                    indices.push(tl as f64);
+                    indices.push(bl as f64);
+                    indices.push(tr as f64);
+                    indices.push(tr as f64);
+                    indices.push(bl as f64);
+                    indices.push(br as f64);
+                }
+            }
+        }
+
+        let mut result = Vec::with_capacity(vertices.len() + indices.len() + 2);
+        result.push(vertices.len() as f64);
+        result.extend_from_slice(&vertices);
+        result.push(indices.len() as f64);
+        result.extend_from_slice(&indices);
+        result
+    }
+
     /// Variant with selectable scaling mode for density mapping.
     /// scale_mode:
     /// 0 = soft log (current default: ln_1p(linear_norm * 10)/ln_1p(10))
@@ -806,6 +2329,7 @@ impl FractalGenerator {
     /// 3 = sqrt(linear_norm)
     /// 4 = gamma 0.5 (sqrt) alias
     /// 5 = gamma 0.25 (4th root)
+    /// 6 = histogram equalization (CDF of the nonzero density distribution)
     #[wasm_bindgen]
     pub fn density_grid_to_rgba_scaled(
         &self,
@@ -819,16 +2343,64 @@ impl FractalGenerator {
             return wasm_bindgen::Clamped(vec![0; width * height * 4]);
         }
 
-        let max_density_val = *density.iter().max().unwrap_or(&1) as f64;
+        let max_density_u32 = *density.iter().max().unwrap_or(&1);
+        let max_density_val = max_density_u32 as f64;
         let mut rgba = vec![0u8; width * height * 4];
         if max_density_val <= 0.0 {
             return wasm_bindgen::Clamped(rgba); // all zeros
         }
 
+        // Mode 6 spreads densities uniformly across [0,1] by the CDF of their distribution,
+        // reclaiming dynamic range when a few bright pixels dominate a long sparse tail. The
+        // per-density mapping is precomputed once so the pixel loop stays a plain lookup.
+        let equalized: Option<Vec<f64>> = if scale_mode == 6 {
+            // Bucket into at most 4096 bins so huge density ranges stay bounded.
+            let bins = (max_density_u32 as usize + 1).min(4096);
+            let bin_of = |d: u32| -> usize {
+                ((d as u64 * (bins as u64 - 1)) / max_density_u32.max(1) as u64) as usize
+            };
+            let mut hist = vec![0u64; bins];
+            let mut total_nonzero = 0u64;
+            for &dv in density.iter() {
+                if dv > 0 {
+                    hist[bin_of(dv)] += 1;
+                    total_nonzero += 1;
+                }
+            }
+            let mut cdf = vec![0u64; bins];
+            let mut acc = 0u64;
+            for (b, &h) in hist.iter().enumerate() {
+                acc += h;
+                cdf[b] = acc;
+            }
+            let cdf_min = hist.iter().position(|&h| h > 0).map(|b| cdf[b]).unwrap_or(0);
+            let denom = (total_nonzero.saturating_sub(cdf_min)).max(1) as f64;
+            Some(
+                (0..bins)
+                    .map(|b| (cdf[b].saturating_sub(cdf_min)) as f64 / denom)
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        // Reuse the same bucketing in the pixel loop so the equalized table stays bounded
+        // to `bins` entries instead of being re-expanded per distinct density.
+        let eq_bins = equalized.as_ref().map(|t| t.len()).unwrap_or(0);
+        let eq_bin_of = |d: u32| -> usize {
+            ((d as u64 * (eq_bins as u64 - 1)) / max_density_u32.max(1) as u64) as usize
+        };
+
         for (i, &dv) in density.iter().enumerate() {
             let d = dv as f64;
             let linear_norm = d / max_density_val;
             let mapped = match scale_mode {
+                6 => {
+                    if dv > 0 {
+                        equalized.as_ref().map(|t| t[eq_bin_of(dv)]).unwrap_or(0.0)
+                    } else {
+                        0.0
+                    }
+                }
                 1 => {
                     // pure log
                     if d > 0.0 {
@@ -1018,14 +2590,567 @@ impl FractalGenerator {
 
             let color = self.apply_color_scheme(normalized, color_scheme);
 
-            rgba[i * 4] = color.0; // R
-            rgba[i * 4 + 1] = color.1; // G
-            rgba[i * 4 + 2] = color.2; // B
-            rgba[i * 4 + 3] = 255; // A
+            rgba[i * 4] = color.0; // R
+            rgba[i * 4 + 1] = color.1; // G
+            rgba[i * 4 + 2] = color.2; // B
+            rgba[i * 4 + 3] = 255; // A
+        }
+
+        rgba
+    }
+
+    /// Reduce a rendered RGBA buffer to an indexed image via median cut.
+    ///
+    /// All opaque pixels start in a single box spanning their RGB bounding range; the box
+    /// whose widest channel (`max − min` over R/G/B) is largest is repeatedly split at the
+    /// median of that channel until `max_colors` boxes exist. Each palette entry is the mean
+    /// color of its box. Every pixel is then mapped to the nearest entry by squared RGB
+    /// distance, and one k-means refinement pass (reassign, recompute centroids) sharpens the
+    /// result. The returned [`QuantizedImage`] carries the packed RGB palette and one index
+    /// per pixel, ready for compact indexed PNG/GIF export of the color-mapped output.
+    #[wasm_bindgen]
+    pub fn quantize_rgba(&self, rgba: &[u8], max_colors: usize) -> QuantizedImage {
+        let max_colors = max_colors.clamp(1, 256);
+        let pixel_count = rgba.len() / 4;
+        if pixel_count == 0 {
+            return QuantizedImage {
+                palette: Vec::new(),
+                indices: Vec::new(),
+            };
+        }
+
+        // Collect the RGB triples (alpha is discarded; these buffers are always opaque).
+        let colors: Vec<[f64; 3]> = (0..pixel_count)
+            .map(|i| {
+                [
+                    rgba[i * 4] as f64,
+                    rgba[i * 4 + 1] as f64,
+                    rgba[i * 4 + 2] as f64,
+                ]
+            })
+            .collect();
+
+        // Median cut: a box is a slice of pixel indices into `colors`.
+        let mut boxes: Vec<Vec<usize>> = vec![(0..pixel_count).collect()];
+        while boxes.len() < max_colors {
+            // Pick the box with the widest single channel.
+            let mut best_box = None;
+            let mut best_extent = -1.0;
+            let mut best_channel = 0usize;
+            for (bi, b) in boxes.iter().enumerate() {
+                if b.len() < 2 {
+                    continue;
+                }
+                for ch in 0..3 {
+                    let mut lo = f64::INFINITY;
+                    let mut hi = f64::NEG_INFINITY;
+                    for &p in b {
+                        lo = lo.min(colors[p][ch]);
+                        hi = hi.max(colors[p][ch]);
+                    }
+                    let extent = hi - lo;
+                    if extent > best_extent {
+                        best_extent = extent;
+                        best_box = Some(bi);
+                        best_channel = ch;
+                    }
+                }
+            }
+
+            let Some(bi) = best_box else { break };
+            if best_extent <= 0.0 {
+                break; // every remaining box is a single color
+            }
+
+            // Sort on the widest channel and split at the median.
+            let mut b = boxes.swap_remove(bi);
+            b.sort_by(|&a, &c| colors[a][best_channel].total_cmp(&colors[c][best_channel]));
+            let mid = b.len() / 2;
+            let right = b.split_off(mid);
+            boxes.push(b);
+            boxes.push(right);
+        }
+
+        // Each palette entry is the mean color of its box.
+        let mut centroids: Vec<[f64; 3]> = boxes
+            .iter()
+            .map(|b| {
+                let mut sum = [0.0f64; 3];
+                for &p in b {
+                    for ch in 0..3 {
+                        sum[ch] += colors[p][ch];
+                    }
+                }
+                let n = b.len().max(1) as f64;
+                [sum[0] / n, sum[1] / n, sum[2] / n]
+            })
+            .collect();
+
+        // Assign pixels to the nearest centroid.
+        let mut indices = vec![0u8; pixel_count];
+        let assign = |centroids: &[[f64; 3]], c: &[f64; 3]| -> u8 {
+            let mut best = 0usize;
+            let mut best_d = f64::INFINITY;
+            for (k, cen) in centroids.iter().enumerate() {
+                let dr = c[0] - cen[0];
+                let dg = c[1] - cen[1];
+                let db = c[2] - cen[2];
+                let d = dr * dr + dg * dg + db * db;
+                if d < best_d {
+                    best_d = d;
+                    best = k;
+                }
+            }
+            best as u8
+        };
+        for (i, c) in colors.iter().enumerate() {
+            indices[i] = assign(&centroids, c);
+        }
+
+        // One k-means refinement pass: recompute centroids, then reassign.
+        let mut sums = vec![[0.0f64; 3]; centroids.len()];
+        let mut counts = vec![0usize; centroids.len()];
+        for (i, c) in colors.iter().enumerate() {
+            let k = indices[i] as usize;
+            for ch in 0..3 {
+                sums[k][ch] += c[ch];
+            }
+            counts[k] += 1;
+        }
+        for k in 0..centroids.len() {
+            if counts[k] > 0 {
+                let n = counts[k] as f64;
+                centroids[k] = [sums[k][0] / n, sums[k][1] / n, sums[k][2] / n];
+            }
+        }
+        for (i, c) in colors.iter().enumerate() {
+            indices[i] = assign(&centroids, c);
+        }
+
+        let palette: Vec<u8> = centroids
+            .iter()
+            .flat_map(|c| {
+                [
+                    c[0].round().clamp(0.0, 255.0) as u8,
+                    c[1].round().clamp(0.0, 255.0) as u8,
+                    c[2].round().clamp(0.0, 255.0) as u8,
+                ]
+            })
+            .collect();
+
+        QuantizedImage { palette, indices }
+    }
+
+    /// Map a density grid straight to an indexed image: median-cut palette plus a
+    /// Floyd–Steinberg-dithered index buffer.
+    ///
+    /// The grid is first color-mapped with `scale_mode` (as in [`Self::density_grid_to_rgba_scaled`]),
+    /// then the truecolor pixels are binned into a histogram keyed by quantized RGB and reduced
+    /// by median cut: starting from a single box, the box with the largest volume is repeatedly
+    /// split along its longest RGB axis at the median-population point until `max_colors`
+    /// (≤256) boxes remain, each represented by its population-weighted average. Remapping walks
+    /// the image in scanline order, picks the nearest palette entry by squared RGB distance and
+    /// diffuses the residual (7/16, 3/16, 5/16, 1/16) through an `f32` error buffer — dithering
+    /// that removes the banding these smooth gradients otherwise show at low palette sizes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn density_grid_to_indexed(
+        &self,
+        density: &[u32],
+        width: usize,
+        height: usize,
+        color_scheme: ColorScheme,
+        scale_mode: u32,
+        max_colors: usize,
+    ) -> QuantizedImage {
+        let max_colors = max_colors.clamp(1, 256);
+        let rgba = self
+            .density_grid_to_rgba_scaled(density, width, height, color_scheme, scale_mode)
+            .0;
+        let pixel_count = width * height;
+        if rgba.len() != pixel_count * 4 || pixel_count == 0 {
+            return QuantizedImage {
+                palette: Vec::new(),
+                indices: Vec::new(),
+            };
+        }
+
+        // Histogram keyed by 5-bits-per-channel quantized RGB, accumulating the exact color sum
+        // so each bucket's representative stays faithful.
+        let mut hist: std::collections::HashMap<u16, (u64, [u64; 3])> =
+            std::collections::HashMap::new();
+        for i in 0..pixel_count {
+            let r = rgba[i * 4];
+            let g = rgba[i * 4 + 1];
+            let b = rgba[i * 4 + 2];
+            let key = (((r >> 3) as u16) << 10) | (((g >> 3) as u16) << 5) | (b >> 3) as u16;
+            let e = hist.entry(key).or_insert((0, [0, 0, 0]));
+            e.0 += 1;
+            e.1[0] += r as u64;
+            e.1[1] += g as u64;
+            e.1[2] += b as u64;
+        }
+
+        // Each distinct color carries its population count into the median-cut boxes.
+        #[derive(Clone)]
+        struct Entry {
+            color: [f64; 3],
+            count: u64,
+        }
+        let entries: Vec<Entry> = hist
+            .values()
+            .map(|(count, sum)| Entry {
+                color: [
+                    sum[0] as f64 / *count as f64,
+                    sum[1] as f64 / *count as f64,
+                    sum[2] as f64 / *count as f64,
+                ],
+                count: *count,
+            })
+            .collect();
+
+        let extent = |b: &[Entry], ch: usize| -> (f64, f64) {
+            let mut lo = f64::INFINITY;
+            let mut hi = f64::NEG_INFINITY;
+            for e in b {
+                lo = lo.min(e.color[ch]);
+                hi = hi.max(e.color[ch]);
+            }
+            (lo, hi)
+        };
+
+        let mut boxes: Vec<Vec<Entry>> = vec![entries];
+        while boxes.len() < max_colors {
+            // Pick the box with the largest volume (product of channel extents).
+            let mut best = None;
+            let mut best_vol = -1.0;
+            for (bi, b) in boxes.iter().enumerate() {
+                if b.len() < 2 {
+                    continue;
+                }
+                let mut vol = 1.0;
+                for ch in 0..3 {
+                    let (lo, hi) = extent(b, ch);
+                    vol *= hi - lo;
+                }
+                if vol > best_vol {
+                    best_vol = vol;
+                    best = Some(bi);
+                }
+            }
+            let Some(bi) = best else { break };
+
+            // Longest axis of the chosen box.
+            let b = &boxes[bi];
+            let mut axis = 0;
+            let mut axis_extent = -1.0;
+            for ch in 0..3 {
+                let (lo, hi) = extent(b, ch);
+                if hi - lo > axis_extent {
+                    axis_extent = hi - lo;
+                    axis = ch;
+                }
+            }
+            if axis_extent <= 0.0 {
+                break;
+            }
+
+            let mut b = boxes.swap_remove(bi);
+            b.sort_by(|a, c| a.color[axis].total_cmp(&c.color[axis]));
+            let total: u64 = b.iter().map(|e| e.count).sum();
+            let mut acc = 0u64;
+            let mut split = 1;
+            for (i, e) in b.iter().enumerate() {
+                acc += e.count;
+                if acc * 2 >= total {
+                    split = (i + 1).clamp(1, b.len() - 1);
+                    break;
+                }
+            }
+            let right = b.split_off(split);
+            boxes.push(b);
+            boxes.push(right);
+        }
+
+        // Population-weighted average color per box.
+        let centroids: Vec<[f64; 3]> = boxes
+            .iter()
+            .map(|b| {
+                let mut sum = [0.0f64; 3];
+                let mut n = 0.0;
+                for e in b {
+                    let c = e.count as f64;
+                    for ch in 0..3 {
+                        sum[ch] += e.color[ch] * c;
+                    }
+                    n += c;
+                }
+                let n = n.max(1.0);
+                [sum[0] / n, sum[1] / n, sum[2] / n]
+            })
+            .collect();
+
+        let nearest = |c: [f32; 3]| -> u8 {
+            let mut best = 0usize;
+            let mut best_d = f64::INFINITY;
+            for (k, cen) in centroids.iter().enumerate() {
+                let dr = c[0] as f64 - cen[0];
+                let dg = c[1] as f64 - cen[1];
+                let db = c[2] as f64 - cen[2];
+                let d = dr * dr + dg * dg + db * db;
+                if d < best_d {
+                    best_d = d;
+                    best = k;
+                }
+            }
+            best as u8
+        };
+
+        // Floyd–Steinberg remap carrying per-channel error in f32.
+        let mut err = vec![0.0f32; pixel_count * 3];
+        let mut indices = vec![0u8; pixel_count];
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+                let old = [
+                    (rgba[i * 4] as f32 + err[i * 3]).clamp(0.0, 255.0),
+                    (rgba[i * 4 + 1] as f32 + err[i * 3 + 1]).clamp(0.0, 255.0),
+                    (rgba[i * 4 + 2] as f32 + err[i * 3 + 2]).clamp(0.0, 255.0),
+                ];
+                let k = nearest(old);
+                indices[i] = k;
+                let cen = centroids[k as usize];
+                let quant = [
+                    old[0] - cen[0] as f32,
+                    old[1] - cen[1] as f32,
+                    old[2] - cen[2] as f32,
+                ];
+                let mut diffuse = |nx: usize, ny: usize, w: f32| {
+                    if nx < width && ny < height {
+                        let ni = (ny * width + nx) * 3;
+                        err[ni] += quant[0] * w;
+                        err[ni + 1] += quant[1] * w;
+                        err[ni + 2] += quant[2] * w;
+                    }
+                };
+                diffuse(x + 1, y, 7.0 / 16.0);
+                if x > 0 {
+                    diffuse(x - 1, y + 1, 3.0 / 16.0);
+                }
+                diffuse(x, y + 1, 5.0 / 16.0);
+                diffuse(x + 1, y + 1, 1.0 / 16.0);
+            }
+        }
+
+        let palette: Vec<u8> = centroids
+            .iter()
+            .flat_map(|c| {
+                [
+                    c[0].round().clamp(0.0, 255.0) as u8,
+                    c[1].round().clamp(0.0, 255.0) as u8,
+                    c[2].round().clamp(0.0, 255.0) as u8,
+                ]
+            })
+            .collect();
+
+        QuantizedImage { palette, indices }
+    }
+}
+
+/// Indexed image produced by [`FractalGenerator::quantize_rgba`]: a packed RGB palette
+/// (`3·n` bytes) and one palette index per pixel.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct QuantizedImage {
+    palette: Vec<u8>,
+    indices: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl QuantizedImage {
+    #[wasm_bindgen(getter)]
+    pub fn palette(&self) -> Vec<u8> {
+        self.palette.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u8> {
+        self.indices.clone()
+    }
+}
+
+/// Chain marching-squares line segments into polylines by matching shared endpoints.
+///
+/// Endpoints are quantized to a small grid so floating-point crossings that coincide compare
+/// equal. Each segment is consumed once; walking from an unused segment and repeatedly
+/// appending the neighbor that shares the current tail produces open or closed polylines.
+fn chain_segments(segments: &[((f64, f64), (f64, f64))]) -> Vec<Vec<(f64, f64)>> {
+    const SCALE: f64 = 1000.0;
+    let key = |p: (f64, f64)| -> (i64, i64) {
+        ((p.0 * SCALE).round() as i64, (p.1 * SCALE).round() as i64)
+    };
+
+    // Index segments by each endpoint key.
+    let mut adjacency: std::collections::HashMap<(i64, i64), Vec<usize>> =
+        std::collections::HashMap::new();
+    for (i, seg) in segments.iter().enumerate() {
+        adjacency.entry(key(seg.0)).or_default().push(i);
+        adjacency.entry(key(seg.1)).or_default().push(i);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut polylines = Vec::new();
+
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let (a, b) = segments[start];
+        let mut line = vec![a, b];
+
+        // Extend forward from the current tail until no unused neighbor matches.
+        loop {
+            let tail = *line.last().unwrap();
+            let mut extended = false;
+            if let Some(neighbors) = adjacency.get(&key(tail)) {
+                for &n in neighbors {
+                    if used[n] {
+                        continue;
+                    }
+                    let (na, nb) = segments[n];
+                    if key(na) == key(tail) {
+                        line.push(nb);
+                    } else {
+                        line.push(na);
+                    }
+                    used[n] = true;
+                    extended = true;
+                    break;
+                }
+            }
+            if !extended {
+                break;
+            }
+        }
+
+        polylines.push(line);
+    }
+
+    polylines
+}
+
+/// Catmull-Rom bicubic kernel (the cubic with `B = 0`, `C = 0.5`), support 2.
+fn catmull_rom(x: f64) -> f64 {
+    let ax = x.abs();
+    if ax < 1.0 {
+        1.5 * ax * ax * ax - 2.5 * ax * ax + 1.0
+    } else if ax < 2.0 {
+        -0.5 * ax * ax * ax + 2.5 * ax * ax - 4.0 * ax + 2.0
+    } else {
+        0.0
+    }
+}
+
+/// Lanczos-3 kernel `sinc(x)·sinc(x/3)` for `|x| < 3`, support 3.
+fn lanczos3(x: f64) -> f64 {
+    if x == 0.0 {
+        return 1.0;
+    }
+    if x.abs() >= 3.0 {
+        return 0.0;
+    }
+    let sinc = |t: f64| {
+        let pt = std::f64::consts::PI * t;
+        pt.sin() / pt
+    };
+    sinc(x) * sinc(x / 3.0)
+}
+
+/// Per-output-sample weight table for one separable pass: the first source index and the
+/// normalized kernel weights covering it.
+struct ResampleTable {
+    start: Vec<isize>,
+    weights: Vec<Vec<f64>>,
+}
+
+/// Precompute the weight table mapping `out_len` output samples back onto `src_len` source
+/// samples. For output `o` the source center is `c = (o + 0.5)·scale − 0.5`; samples in
+/// `[⌈c − support⌉, ⌊c + support⌋]` are weighted by the kernel at `src − c`, clamped at the
+/// edges and normalized by the weight sum.
+fn build_table(src_len: usize, out_len: usize, filter: u32) -> ResampleTable {
+    let scale = src_len as f64 / out_len as f64;
+    let support = if filter == 1 { 3.0 } else { 2.0 };
+    let kernel = |x: f64| if filter == 1 { lanczos3(x) } else { catmull_rom(x) };
+
+    let mut start = Vec::with_capacity(out_len);
+    let mut weights = Vec::with_capacity(out_len);
+    for o in 0..out_len {
+        let c = (o as f64 + 0.5) * scale - 0.5;
+        let lo = (c - support).ceil() as isize;
+        let hi = (c + support).floor() as isize;
+        let mut w = Vec::with_capacity((hi - lo + 1).max(0) as usize);
+        let mut sum = 0.0;
+        for s in lo..=hi {
+            let weight = kernel(s as f64 - c);
+            w.push(weight);
+            sum += weight;
+        }
+        if sum != 0.0 {
+            for weight in w.iter_mut() {
+                *weight /= sum;
+            }
+        }
+        start.push(lo);
+        weights.push(w);
+    }
+    ResampleTable { start, weights }
+}
+
+/// Downscale an RGBA buffer from `w × h` to `out_w × out_h` with a two-pass separable
+/// convolution. The alpha channel is left fully opaque; negative lobe undershoot is clamped.
+fn resample_rgba(src: &[u8], w: usize, h: usize, out_w: usize, out_h: usize, filter: u32) -> Vec<u8> {
+    // Horizontal pass: w × h -> out_w × h, working in f64 RGB.
+    let xt = build_table(w, out_w, filter);
+    let mut horiz = vec![0.0f64; out_w * h * 3];
+    for y in 0..h {
+        for ox in 0..out_w {
+            let mut acc = [0.0f64; 3];
+            for (k, &weight) in xt.weights[ox].iter().enumerate() {
+                let sx = (xt.start[ox] + k as isize).clamp(0, w as isize - 1) as usize;
+                let base = (y * w + sx) * 4;
+                acc[0] += weight * src[base] as f64;
+                acc[1] += weight * src[base + 1] as f64;
+                acc[2] += weight * src[base + 2] as f64;
+            }
+            let dst = (y * out_w + ox) * 3;
+            horiz[dst] = acc[0];
+            horiz[dst + 1] = acc[1];
+            horiz[dst + 2] = acc[2];
         }
+    }
 
-        rgba
+    // Vertical pass: out_w × h -> out_w × out_h.
+    let yt = build_table(h, out_h, filter);
+    let mut out = vec![0u8; out_w * out_h * 4];
+    for oy in 0..out_h {
+        for ox in 0..out_w {
+            let mut acc = [0.0f64; 3];
+            for (k, &weight) in yt.weights[oy].iter().enumerate() {
+                let sy = (yt.start[oy] + k as isize).clamp(0, h as isize - 1) as usize;
+                let src_idx = (sy * out_w + ox) * 3;
+                acc[0] += weight * horiz[src_idx];
+                acc[1] += weight * horiz[src_idx + 1];
+                acc[2] += weight * horiz[src_idx + 2];
+            }
+            let dst = (oy * out_w + ox) * 4;
+            out[dst] = acc[0].round().clamp(0.0, 255.0) as u8;
+            out[dst + 1] = acc[1].round().clamp(0.0, 255.0) as u8;
+            out[dst + 2] = acc[2].round().clamp(0.0, 255.0) as u8;
+            out[dst + 3] = 255;
+        }
     }
+
+    out
 }
 
 // -----------------------------------------------------------------------------
@@ -1062,6 +3187,10 @@ pub struct ChaoticAccumulator {
     map_denom: f64,
     map_color_scheme: ColorScheme,
     map_max_density: u16,
+    // Temporal afterglow: when `Some`, each frame blends as `acc = max(new, acc·decay)` to
+    // produce phosphor-style motion trails; `None` preserves the exact static behavior.
+    afterglow: Option<Vec<f64>>,
+    afterglow_decay: f64,
 }
 
 #[wasm_bindgen]
@@ -1118,6 +3247,8 @@ impl ChaoticAccumulator {
                 map_denom: 1.0,
                 map_color_scheme: ColorScheme::Cubehelix,
                 map_max_density: 0,
+                afterglow: None,
+                afterglow_decay: 0.85,
             }
         } else {
             ChaoticAccumulator {
@@ -1142,6 +3273,8 @@ impl ChaoticAccumulator {
                 map_denom: 1.0,
                 map_color_scheme: ColorScheme::Cubehelix,
                 map_max_density: 0,
+                afterglow: None,
+                afterglow_decay: 0.85,
             }
         }
     }
@@ -1342,6 +3475,113 @@ impl ChaoticAccumulator {
         )
     }
 
+    /// Enable temporal afterglow with the given per-frame `decay ∈ (0,1)`, starting from an
+    /// empty accumulation buffer. Subsequent [`Self::to_rgba_afterglow`] calls leave phosphor
+    /// trails as parameters are swept between frames.
+    #[wasm_bindgen]
+    pub fn enable_afterglow(&mut self, decay: f64) {
+        self.afterglow_decay = decay.clamp(0.0, 0.999);
+        self.afterglow = Some(vec![0.0; self.width * self.height]);
+    }
+
+    /// Disable afterglow and drop the accumulation buffer, restoring exact static behavior.
+    #[wasm_bindgen]
+    pub fn disable_afterglow(&mut self) {
+        self.afterglow = None;
+    }
+
+    /// Map the current density to RGBA with log-soft scaling, blending through the afterglow
+    /// buffer when enabled.
+    ///
+    /// With afterglow on, the persistent buffer updates as `acc = max(new_density, acc·decay)`
+    /// per cell and the existing log normalization + colormap run on `acc`, yielding motion
+    /// trails. With afterglow off this is identical to [`Self::to_rgba_log_soft`].
+    #[wasm_bindgen]
+    pub fn to_rgba_afterglow(&mut self, color_scheme: ColorScheme, softness: f64) -> Vec<u8> {
+        if self.afterglow.is_none() {
+            return self.to_rgba_log_soft(color_scheme, softness).0;
+        }
+
+        let density_grid = self.density();
+        let decay = self.afterglow_decay;
+        let acc = self.afterglow.as_mut().unwrap();
+        if acc.len() != density_grid.len() {
+            *acc = vec![0.0; density_grid.len()];
+        }
+        let mut max_acc = 0.0f64;
+        for (a, &d) in acc.iter_mut().zip(density_grid.iter()) {
+            *a = (d as f64).max(*a * decay);
+            if *a > max_acc {
+                max_acc = *a;
+            }
+        }
+
+        let mut rgba = vec![0u8; self.width * self.height * 4];
+        if max_acc <= 0.0 {
+            return rgba;
+        }
+        let s = if softness.is_finite() && softness > 0.0 {
+            softness
+        } else {
+            1.0
+        };
+        let denom = (1.0 + s * max_acc).ln();
+        let gen = FractalGenerator::new();
+        for (i, &a) in acc.iter().enumerate() {
+            let mapped = if a > 0.0 && denom > 0.0 {
+                ((1.0 + s * a).ln() / denom).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let (r, g, b) = gen.apply_color_scheme(mapped, color_scheme);
+            let base = i * 4;
+            rgba[base] = r;
+            rgba[base + 1] = g;
+            rgba[base + 2] = b;
+            rgba[base + 3] = 255;
+        }
+        rgba
+    }
+
+    /// Map the current (supersampled) density to RGBA and downscale it with a proper
+    /// reconstruction filter.
+    ///
+    /// The accumulator grid is treated as an `ss×` supersampled buffer: its `width × height`
+    /// log-soft-mapped pixels are resampled down to `(width/ss) × (height/ss)` using a
+    /// two-pass separable convolution (horizontal then vertical, so cost is O(pixels·kernel)).
+    /// `filter` selects the kernel — `0` = Catmull-Rom bicubic (support 2), `1` = Lanczos3
+    /// (`sinc(x)·sinc(x/3)`, support 3); both undershoot, so negative results are clamped to 0.
+    /// This yields visibly smoother exports than box-averaging at the same display resolution.
+    #[wasm_bindgen]
+    pub fn to_rgba_downsampled(
+        &self,
+        color_scheme: ColorScheme,
+        softness: f64,
+        ss: usize,
+        filter: u32,
+    ) -> Vec<u8> {
+        let ss = ss.max(1);
+        let out_w = self.width / ss;
+        let out_h = self.height / ss;
+        if out_w == 0 || out_h == 0 {
+            return vec![0u8; out_w * out_h * 4];
+        }
+
+        let gen = FractalGenerator::new();
+        let density_grid = self.density();
+        let hi = gen
+            .density_grid_to_rgba_log_soft(
+                &density_grid,
+                self.width,
+                self.height,
+                color_scheme,
+                softness,
+            )
+            .0;
+
+        resample_rgba(&hi, self.width, self.height, out_w, out_h, filter)
+    }
+
     /// Fill the internal reusable RGBA buffer from current density with log-soft mapping (zero-copy view ready)
     #[wasm_bindgen]
     pub fn fill_rgba_log_soft(&mut self, color_scheme: ColorScheme, softness: f64) {
@@ -1548,6 +3788,267 @@ fn map_cubic(args: &[f64], x: f64, y: f64) -> f64 {
 
 // Internal implementations
 impl FractalGenerator {
+    /// Convolve an `f64` buffer with a normalized 1D Gaussian along rows then columns.
+    /// Kernel radius is `⌈3σ⌉`; border samples clamp to the edge.
+    fn separable_gaussian(&self, buf: &[f64], width: usize, height: usize, sigma: f64) -> Vec<f64> {
+        if !(sigma > 0.0) || buf.len() != width * height {
+            return buf.to_vec();
+        }
+        let radius = (3.0 * sigma).ceil() as isize;
+        let mut kernel = Vec::with_capacity((2 * radius + 1) as usize);
+        let denom = 2.0 * sigma * sigma;
+        let mut sum = 0.0;
+        for i in -radius..=radius {
+            let w = (-(i * i) as f64 / denom).exp();
+            kernel.push(w);
+            sum += w;
+        }
+        for w in kernel.iter_mut() {
+            *w /= sum;
+        }
+
+        // Horizontal pass.
+        let mut tmp = vec![0.0; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let mut acc = 0.0;
+                for (k, &w) in kernel.iter().enumerate() {
+                    let sx = (x as isize + k as isize - radius).clamp(0, width as isize - 1) as usize;
+                    acc += w * buf[y * width + sx];
+                }
+                tmp[y * width + x] = acc;
+            }
+        }
+
+        // Vertical pass.
+        let mut out = vec![0.0; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let mut acc = 0.0;
+                for (k, &w) in kernel.iter().enumerate() {
+                    let sy = (y as isize + k as isize - radius).clamp(0, height as isize - 1) as usize;
+                    acc += w * tmp[sy * width + x];
+                }
+                out[y * width + x] = acc;
+            }
+        }
+        out
+    }
+
+    /// Sample the Mandelbulb escape field on a `resolution³` voxel grid over `[min, max]³`,
+    /// laid out `x + y·res + z·res²`.
+    fn mandelbulb_field(
+        &self,
+        resolution: usize,
+        power: f64,
+        max_iterations: usize,
+        min: f64,
+        max: f64,
+    ) -> Vec<f32> {
+        let res = resolution.max(2);
+        let step = (max - min) / (res - 1) as f64;
+        let mut field = Vec::with_capacity(res * res * res);
+        for iz in 0..res {
+            for iy in 0..res {
+                for ix in 0..res {
+                    let cx = min + ix as f64 * step;
+                    let cy = min + iy as f64 * step;
+                    let cz = min + iz as f64 * step;
+                    field.push(self.mandelbulb_point(cx, cy, cz, power, max_iterations) as f32);
+                }
+            }
+        }
+        field
+    }
+
+    /// Escape iteration count for a single Mandelbulb voxel; `max_iterations` when inside.
+    fn mandelbulb_point(
+        &self,
+        cx: f64,
+        cy: f64,
+        cz: f64,
+        power: f64,
+        max_iterations: usize,
+    ) -> usize {
+        let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+        for iteration in 0..max_iterations {
+            let r = (x * x + y * y + z * z).sqrt();
+            if r > 2.0 {
+                return iteration;
+            }
+            if r == 0.0 {
+                x = cx;
+                y = cy;
+                z = cz;
+                continue;
+            }
+            let theta = (z / r).acos();
+            let phi = y.atan2(x);
+            let rp = r.powf(power);
+            let sin_t = (theta * power).sin();
+            x = rp * sin_t * (phi * power).cos() + cx;
+            y = rp * sin_t * (phi * power).sin() + cy;
+            z = rp * (theta * power).cos() + cz;
+        }
+        max_iterations
+    }
+
+    /// Triangulate the `iso` isosurface of a scalar field via a per-cube tetrahedral
+    /// decomposition — watertight and free of the ambiguous-face artifacts the raw cube
+    /// tables can produce. Triangles are flat vertex triples in world coordinates.
+    fn marching_cubes(
+        &self,
+        field: &[f32],
+        resolution: usize,
+        min: f64,
+        max: f64,
+        iso: f32,
+    ) -> Vec<[Point3D; 3]> {
+        let res = resolution;
+        if res < 2 {
+            return Vec::new();
+        }
+        let step = (max - min) / (res - 1) as f64;
+        let at = |x: usize, y: usize, z: usize| field[x + y * res + z * res * res];
+        let pos = |x: usize, y: usize, z: usize| {
+            Point3D::new(min + x as f64 * step, min + y as f64 * step, min + z as f64 * step)
+        };
+
+        const CORNERS: [(usize, usize, usize); 8] = [
+            (0, 0, 0),
+            (1, 0, 0),
+            (1, 1, 0),
+            (0, 1, 0),
+            (0, 0, 1),
+            (1, 0, 1),
+            (1, 1, 1),
+            (0, 1, 1),
+        ];
+        const TETS: [[usize; 4]; 6] = [
+            [0, 5, 1, 6],
+            [0, 1, 2, 6],
+            [0, 2, 3, 6],
+            [0, 3, 7, 6],
+            [0, 7, 4, 6],
+            [0, 4, 5, 6],
+        ];
+
+        let interp = |pa: Point3D, pb: Point3D, va: f32, vb: f32| -> Point3D {
+            if (va - vb).abs() < f32::EPSILON {
+                return pa;
+            }
+            let mu = ((iso - va) / (vb - va)) as f64;
+            Point3D::new(
+                pa.x + mu * (pb.x - pa.x),
+                pa.y + mu * (pb.y - pa.y),
+                pa.z + mu * (pb.z - pa.z),
+            )
+        };
+
+        let mut triangles = Vec::new();
+        for z in 0..res - 1 {
+            for y in 0..res - 1 {
+                for x in 0..res - 1 {
+                    let mut values = [0.0f32; 8];
+                    let mut positions = [Point3D::zero(); 8];
+                    for (i, &(dx, dy, dz)) in CORNERS.iter().enumerate() {
+                        values[i] = at(x + dx, y + dy, z + dz);
+                        positions[i] = pos(x + dx, y + dy, z + dz);
+                    }
+                    for tet in &TETS {
+                        Self::march_tetrahedron(tet, &values, &positions, iso, &interp, &mut triangles);
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Triangulate the isosurface crossing one tetrahedron (see [`marching_cubes`]).
+    fn march_tetrahedron(
+        tet: &[usize; 4],
+        values: &[f32; 8],
+        positions: &[Point3D; 8],
+        iso: f32,
+        interp: &impl Fn(Point3D, Point3D, f32, f32) -> Point3D,
+        out: &mut Vec<[Point3D; 3]>,
+    ) {
+        let v = [values[tet[0]], values[tet[1]], values[tet[2]], values[tet[3]]];
+        let p = [positions[tet[0]], positions[tet[1]], positions[tet[2]], positions[tet[3]]];
+        let mut mask = 0u8;
+        for (i, &val) in v.iter().enumerate() {
+            if val < iso {
+                mask |= 1 << i;
+            }
+        }
+        let edge = |a: usize, b: usize| interp(p[a], p[b], v[a], v[b]);
+        match mask {
+            0x00 | 0x0F => {}
+            0x01 | 0x0E => out.push([edge(0, 1), edge(0, 2), edge(0, 3)]),
+            0x02 | 0x0D => out.push([edge(1, 0), edge(1, 3), edge(1, 2)]),
+            0x04 | 0x0B => out.push([edge(2, 0), edge(2, 1), edge(2, 3)]),
+            0x08 | 0x07 => out.push([edge(3, 0), edge(3, 2), edge(3, 1)]),
+            0x03 | 0x0C => {
+                let a = edge(0, 3);
+                let b = edge(0, 2);
+                let c = edge(1, 3);
+                let d = edge(1, 2);
+                out.push([a, b, c]);
+                out.push([b, d, c]);
+            }
+            0x05 | 0x0A => {
+                let a = edge(0, 1);
+                let b = edge(2, 3);
+                let c = edge(0, 3);
+                let d = edge(1, 2);
+                out.push([a, d, b]);
+                out.push([a, b, c]);
+            }
+            0x06 | 0x09 => {
+                let a = edge(0, 1);
+                let b = edge(1, 3);
+                let c = edge(2, 3);
+                let d = edge(0, 2);
+                out.push([a, b, c]);
+                out.push([a, c, d]);
+            }
+            _ => {}
+        }
+    }
+
+    /// Serialize a triangle mesh to binary STL bytes with per-facet normals.
+    fn mesh_to_binary_stl(&self, triangles: &[[Point3D; 3]]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(84 + triangles.len() * 50);
+        bytes.extend_from_slice(&[0u8; 80]);
+        bytes.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+
+        let push = |bytes: &mut Vec<u8>, v: f32| bytes.extend_from_slice(&v.to_le_bytes());
+        for tri in triangles {
+            let (a, b, c) = (tri[0], tri[1], tri[2]);
+            let (ux, uy, uz) = (b.x - a.x, b.y - a.y, b.z - a.z);
+            let (vx, vy, vz) = (c.x - a.x, c.y - a.y, c.z - a.z);
+            let mut nx = uy * vz - uz * vy;
+            let mut ny = uz * vx - ux * vz;
+            let mut nz = ux * vy - uy * vx;
+            let len = (nx * nx + ny * ny + nz * nz).sqrt();
+            if len > 0.0 {
+                nx /= len;
+                ny /= len;
+                nz /= len;
+            }
+            push(&mut bytes, nx as f32);
+            push(&mut bytes, ny as f32);
+            push(&mut bytes, nz as f32);
+            for p in tri {
+                push(&mut bytes, p.x as f32);
+                push(&mut bytes, p.y as f32);
+                push(&mut bytes, p.z as f32);
+            }
+            bytes.extend_from_slice(&[0u8; 2]);
+        }
+        bytes
+    }
+
     fn chaos_game_internal(
         &mut self,
         vertices: Vec<Point2D>,
@@ -1633,6 +4134,52 @@ impl FractalGenerator {
         points
     }
 
+    fn ifs_fractal_recurrent_internal(
+        &mut self,
+        start: Point2D,
+        iterations: usize,
+        transforms: Vec<AffineTransform>,
+        transition: Vec<f64>,
+        use_borke_mode: bool,
+    ) -> Vec<Point2D> {
+        let n = transforms.len();
+        let mut points = Vec::with_capacity(iterations);
+        let mut current = start;
+        points.push(current);
+
+        // Validate the matrix: square with every row summing to 1. If it is malformed we
+        // drop back to independent uniform selection so a bad matrix never panics.
+        let valid = n > 0
+            && transition.len() == n * n
+            && (0..n).all(|i| {
+                let row_sum: f64 = transition[i * n..(i + 1) * n].iter().sum();
+                (row_sum - 1.0).abs() < 1e-6
+            });
+
+        let uniform = vec![1.0 / n.max(1) as f64; n];
+        let mut prev_index = 0usize;
+
+        for _ in 1..iterations {
+            let transform_index = if valid {
+                self.select_transform(&transition[prev_index * n..(prev_index + 1) * n])
+            } else {
+                self.select_transform(&uniform)
+            };
+            prev_index = transform_index;
+            let transform = transforms[transform_index];
+
+            current = if use_borke_mode {
+                transform.apply_borke(current)
+            } else {
+                transform.apply_regular(current)
+            };
+
+            points.push(current);
+        }
+
+        points
+    }
+
     fn select_vertex(&mut self, vertex_count: usize, rule: &mut Rule) -> usize {
         loop {
             let index = self.rng.gen_range(0..vertex_count) as i32;
@@ -1658,10 +4205,13 @@ impl FractalGenerator {
     }
 
     fn apply_color_scheme(&self, normalized: f64, scheme: ColorScheme) -> (u8, u8, u8) {
-        // Primary path: LUT lookup
-        let idx = lut_index(normalized);
-        if let Some((r, g, b)) = (*LUTS).get(&scheme).and_then(|lut| lut.get(idx).copied()) {
-            return (r, g, b);
+        // Primary path: LUT lookup (skipped when HSV interpolation is requested, since the
+        // shared LUTs are baked in RGB space).
+        if !self.hsv_interpolation {
+            let idx = lut_index(normalized);
+            if let Some((r, g, b)) = (*LUTS).get(&scheme).and_then(|lut| lut.get(idx).copied()) {
+                return (r, g, b);
+            }
         }
         // Fallback: compute directly (should not normally happen)
         let clamped = normalized.clamp(0.0, 1.0);
@@ -1803,10 +4353,110 @@ impl FractalGenerator {
         let frac = scaled - idx as f64;
         let (r1, g1, b1) = stops[idx];
         let (r2, g2, b2) = stops[idx + 1];
+        if self.hsv_interpolation {
+            return self.lerp_hsv((r1, g1, b1), (r2, g2, b2), frac);
+        }
+        let lerp = |a: u8, b: u8| -> u8 { (a as f64 + (b as f64 - a as f64) * frac).round() as u8 };
+        (lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+    }
+
+    /// Interpolate a color from positioned stops: given normalized `t`, binary-search for the
+    /// bracketing stops and lerp by `(t − p_i)/(p_{i+1} − p_i)`. Positions must be ascending in
+    /// `[0,1]` but need not be evenly spaced. Honors the HSV interpolation toggle.
+    fn gradient_color_stops(&self, t: f64, positions: &[f64], colors: &[(u8, u8, u8)]) -> (u8, u8, u8) {
+        if colors.is_empty() {
+            return (0, 0, 0);
+        }
+        let t = t.clamp(0.0, 1.0);
+        if t <= positions[0] {
+            return colors[0];
+        }
+        let last = colors.len() - 1;
+        if t >= positions[last] {
+            return colors[last];
+        }
+        // partition_point finds the first stop strictly greater than t.
+        let hi = positions.partition_point(|&p| p <= t).clamp(1, last);
+        let lo = hi - 1;
+        let span = positions[hi] - positions[lo];
+        let frac = if span > 0.0 { (t - positions[lo]) / span } else { 0.0 };
+        if self.hsv_interpolation {
+            return self.lerp_hsv(colors[lo], colors[hi], frac);
+        }
+        let (r1, g1, b1) = colors[lo];
+        let (r2, g2, b2) = colors[hi];
         let lerp = |a: u8, b: u8| -> u8 { (a as f64 + (b as f64 - a as f64) * frac).round() as u8 };
         (lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
     }
 
+    /// Interpolate between two RGB stops through HSV space by `frac ∈ [0,1]`. Saturation and
+    /// value lerp linearly; hue follows the shortest arc (wrapping when the gap exceeds half
+    /// the wheel), so near-complementary stops stay saturated instead of passing through gray.
+    fn lerp_hsv(&self, a: (u8, u8, u8), b: (u8, u8, u8), frac: f64) -> (u8, u8, u8) {
+        let (h1, s1, v1) = self.rgb_to_hsv(a);
+        let (h2, s2, v2) = self.rgb_to_hsv(b);
+
+        // Move hue along the shortest arc.
+        let (mut ha, mut hb) = (h1, h2);
+        if (hb - ha).abs() > 0.5 {
+            if ha < hb {
+                ha += 1.0;
+            } else {
+                hb += 1.0;
+            }
+        }
+        let mut h = ha + (hb - ha) * frac;
+        h -= h.floor(); // wrap into [0,1)
+
+        let s = s1 + (s2 - s1) * frac;
+        let v = v1 + (v2 - v1) * frac;
+        self.hsv_to_rgb(h, s, v)
+    }
+
+    /// Convert an 8-bit RGB triple to HSV with all components in [0,1].
+    fn rgb_to_hsv(&self, (r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+        let r = r as f64 / 255.0;
+        let g = g as f64 / 255.0;
+        let b = b as f64 / 255.0;
+        let v = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = v - min;
+        let s = if v > 0.0 { delta / v } else { 0.0 };
+        let h = if delta <= 0.0 {
+            0.0
+        } else if v == r {
+            ((g - b) / delta) % 6.0
+        } else if v == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        } / 6.0;
+        (h.rem_euclid(1.0), s, v)
+    }
+
+    /// Convert HSV (components in [0,1]) back to an 8-bit RGB triple via the sextant method.
+    fn hsv_to_rgb(&self, h: f64, s: f64, v: f64) -> (u8, u8, u8) {
+        let h = h.rem_euclid(1.0) * 6.0;
+        let i = h.floor();
+        let f = h - i;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+        let (r, g, b) = match i as i64 % 6 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+        (
+            (r * 255.0).round() as u8,
+            (g * 255.0).round() as u8,
+            (b * 255.0).round() as u8,
+        )
+    }
+
     fn plasma_colormap(&self, t: f64) -> (u8, u8, u8) {
         // Approximate Matplotlib plasma
         const STOPS: &[(u8, u8, u8)] = &[
@@ -2187,6 +4837,72 @@ impl FractalPresets {
         Array::of2(&0.8.into(), &0.2.into())
     }
 
+    /// Classic De Jong attractor coefficients `[a, b, c, d]` for
+    /// [`FractalGenerator::de_jong_density`].
+    pub fn de_jong() -> Array {
+        let coeffs = Array::new();
+        coeffs.push(&1.4.into());
+        coeffs.push(&(-2.3).into());
+        coeffs.push(&2.4.into());
+        coeffs.push(&(-2.1).into());
+        coeffs
+    }
+
+    /// Classic Clifford attractor coefficients `[a, b, c, d]` for
+    /// [`FractalGenerator::clifford_density`].
+    pub fn clifford() -> Array {
+        let coeffs = Array::new();
+        coeffs.push(&(-1.4).into());
+        coeffs.push(&1.6.into());
+        coeffs.push(&1.0.into());
+        coeffs.push(&0.7.into());
+        coeffs
+    }
+
+    /// Procedurally generate a random IFS from a stick-breaking prior.
+    ///
+    /// Returns a two-element `Array` `[transforms, probabilities]` in the same format as the
+    /// hand-tuned presets (`transforms` is an array of `[a, b, c, d, e, f]` affine maps,
+    /// `probabilities` a parallel weight vector). The weights come from a stick-breaking
+    /// construction with `v_k ~ Beta(1, alpha)`: small `alpha` yields a few dominant
+    /// transforms, large `alpha` near-uniform weights. Affine coefficients are drawn in a
+    /// contractive range so the system converges. `seed` makes the result reproducible.
+    pub fn random_ifs(n: usize, alpha: f64, seed: u64) -> Array {
+        let n = n.max(1);
+        let alpha = if alpha > 0.0 { alpha } else { 1.0 };
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+
+        let transforms = Array::new();
+        for _ in 0..n {
+            let t = Array::new();
+            // Contractive linear part in [-0.6, 0.6], translation in [-1, 1].
+            t.push(&(1.2 * rng.gen::<f64>() - 0.6).into());
+            t.push(&(1.2 * rng.gen::<f64>() - 0.6).into());
+            t.push(&(1.2 * rng.gen::<f64>() - 0.6).into());
+            t.push(&(1.2 * rng.gen::<f64>() - 0.6).into());
+            t.push(&(2.0 * rng.gen::<f64>() - 1.0).into());
+            t.push(&(2.0 * rng.gen::<f64>() - 1.0).into());
+            transforms.push(&t);
+        }
+
+        // Stick-breaking: p_k = v_k · Π_{j<k}(1 - v_j), leftover stick to p_N.
+        // Beta(1, alpha) is sampled by inverse-CDF as 1 - U^(1/alpha).
+        let probs = Array::new();
+        let mut remaining = 1.0;
+        for k in 0..n {
+            if k == n - 1 {
+                probs.push(&remaining.into());
+            } else {
+                let v = 1.0 - rng.gen::<f64>().powf(1.0 / alpha);
+                let p = v * remaining;
+                probs.push(&p.into());
+                remaining -= p;
+            }
+        }
+
+        Array::of2(&transforms, &probs)
+    }
+
     /// Get Barnsley fern IFS parameters
     pub fn barnsley_fern() -> Array {
         let transforms = Array::new();
@@ -2415,8 +5131,14 @@ impl FractalPresets {
 impl FractalGenerator {
     /// Generate random arguments between -1.2 and 1.2
     fn get_random_args(&self, n: usize) -> Vec<f64> {
-        let mut rng = thread_rng();
-        (0..n).map(|_| 2.4 * rng.gen::<f64>() - 1.2).collect()
+        // Draw from the seeded search RNG when one is installed, otherwise from thread_rng.
+        let mut guard = self.search_rng.borrow_mut();
+        if let Some(rng) = guard.as_mut() {
+            (0..n).map(|_| 2.4 * rng.gen::<f64>() - 1.2).collect()
+        } else {
+            let mut rng = thread_rng();
+            (0..n).map(|_| 2.4 * rng.gen::<f64>() - 1.2).collect()
+        }
     }
 
     // (map functions now shared: map_quadratic / map_cubic)
@@ -2554,8 +5276,17 @@ impl FractalGenerator {
         let mut c = 0.0;
         let mut count = 0;
 
+        let log2 = 2.0_f64.ln();
+        // Aitken Δ² acceleration of the running max-LE estimate: record the estimate every
+        // `checkpoint` iterations, extrapolate the last three checkpoints, and stop once the
+        // accelerated value settles. `n_test` remains the hard upper bound.
+        let checkpoint = 500usize;
+        let tol = 1e-5;
+        let mut history: Vec<f64> = Vec::with_capacity(3);
+        let mut y_prev: Option<f64> = None;
+
         // Begin Lyapunov exponent estimation
-        for _ in 0..n_test {
+        for k in 1..=n_test {
             let (xp, yp) = (x, y);
             let m = if is_cubic {
                 x = map_cubic(args1, xp, yp);
@@ -2604,10 +5335,33 @@ impl FractalGenerator {
             max_le += sqrt_dot_11.ln();
             min_le += sqrt_dot_22.ln();
             c += self.determinant(m).abs().ln();
+
+            if k % checkpoint == 0 {
+                let x_k = max_le / k as f64 / log2;
+                history.push(x_k);
+                if history.len() == 3 {
+                    let (x0, x1, x2) = (history[0], history[1], history[2]);
+                    let denom = x2 - 2.0 * x1 + x0;
+                    // Skip acceleration when the denominator is near zero (division blows up).
+                    if denom.abs() > 1e-12 {
+                        let y = x0 - (x1 - x0).powi(2) / denom;
+                        if y.is_finite() {
+                            if let Some(prev) = y_prev {
+                                if (y - prev).abs() < tol {
+                                    // Converged early: return the accelerated estimate.
+                                    let k_f = k as f64;
+                                    return (y, min_le / k_f / log2, c / k_f / log2);
+                                }
+                            }
+                            y_prev = Some(y);
+                        }
+                    }
+                    history.remove(0);
+                }
+            }
         }
 
         let n_test_f = n_test as f64;
-        let log2 = 2.0_f64.ln();
 
         max_le = max_le / n_test_f / log2;
         min_le = min_le / n_test_f / log2;
@@ -2720,11 +5474,32 @@ impl FractalGenerator {
                     min_lyapunov: min_le,
                     fractal_dimension: fd,
                     is_cubic,
+                    seed: 0,
                 };
             }
         }
     }
 
+    /// Find a random chaotic map reproducibly from a fixed seed.
+    ///
+    /// Installs a seeded RNG for the duration of the search so the same `seed`
+    /// always yields the same map; the returned result carries that seed so the
+    /// discovery can be replayed byte-for-byte.
+    #[wasm_bindgen]
+    pub fn find_random_chaos_seeded(
+        &self,
+        seed: u64,
+        n_plot: usize,
+        n_test: usize,
+        is_cubic: bool,
+    ) -> ChaoticMapResult {
+        *self.search_rng.borrow_mut() = Some(rand::rngs::StdRng::seed_from_u64(seed));
+        let mut result = self.find_random_chaos_extended(n_plot, n_test, 0, false, is_cubic);
+        *self.search_rng.borrow_mut() = None;
+        result.seed = seed;
+        result
+    }
+
     /// Find a random chaotic map
     #[wasm_bindgen]
     pub fn find_random_chaos(&self, n_plot: usize, n_test: usize, is_cubic: bool) -> Vec<f64> {
@@ -2796,14 +5571,77 @@ impl FractalGenerator {
             result.push(point[1]);
         }
 
-        result
+        result
+    }
+
+    /// Generate points from given chaotic map parameters in batches
+    /// Returns density grid that can be merged with other batches
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_chaotic_map_batch_to_density(
+        &self,
+        x_params: &[f64],
+        y_params: &[f64],
+        n_points: usize,
+        is_cubic: bool,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        start_iteration: usize,
+    ) -> Vec<u32> {
+        // Generate points using the same iteration logic but starting from a specific iteration
+        let mut x = 0.05;
+        let mut y = 0.05;
+
+        // Advance to the starting iteration (skip iterations to maintain continuity)
+        for _ in 0..start_iteration {
+            let (xp, yp) = (x, y);
+            if is_cubic {
+                x = map_cubic(x_params, xp, yp);
+                y = map_cubic(y_params, xp, yp);
+            } else {
+                x = map_quadratic(x_params, xp, yp);
+                y = map_quadratic(y_params, xp, yp);
+            }
+        }
+
+        // Create density grid
+        let mut density = vec![0u32; width * height];
+
+        // Generate the batch of points and directly add to density grid
+        for _ in 0..n_points {
+            let (xp, yp) = (x, y);
+            if is_cubic {
+                x = map_cubic(x_params, xp, yp);
+                y = map_cubic(y_params, xp, yp);
+            } else {
+                x = map_quadratic(x_params, xp, yp);
+                y = map_quadratic(y_params, xp, yp);
+            }
+
+            // Add point to density grid
+            let pixel_x = ((x - min_x) / (max_x - min_x) * width as f64) as usize;
+            let pixel_y = ((y - min_y) / (max_y - min_y) * height as f64) as usize;
+
+            if pixel_x < width && pixel_y < height {
+                density[pixel_y * width + pixel_x] += 1;
+            }
+        }
+
+        density
     }
 
-    /// Generate points from given chaotic map parameters in batches
-    /// Returns density grid that can be merged with other batches
+    /// Accumulate a density grid in the rotated frame produced by [`pca_frame_from_points`].
+    ///
+    /// Each point is translated to the cloud centroid and projected onto the principal axes
+    /// before binning, so an elongated or tilted attractor is re-centered and axis-aligned to
+    /// fill the grid. `min_u/max_u/min_v/max_v` are the projected bounds from the [`PcaFrame`].
     #[wasm_bindgen]
     #[allow(clippy::too_many_arguments)]
-    pub fn generate_chaotic_map_batch_to_density(
+    pub fn generate_chaotic_map_batch_to_density_framed(
         &self,
         x_params: &[f64],
         y_params: &[f64],
@@ -2811,17 +5649,18 @@ impl FractalGenerator {
         is_cubic: bool,
         width: usize,
         height: usize,
-        min_x: f64,
-        max_x: f64,
-        min_y: f64,
-        max_y: f64,
+        mean_x: f64,
+        mean_y: f64,
+        angle: f64,
+        min_u: f64,
+        max_u: f64,
+        min_v: f64,
+        max_v: f64,
         start_iteration: usize,
     ) -> Vec<u32> {
-        // Generate points using the same iteration logic but starting from a specific iteration
         let mut x = 0.05;
         let mut y = 0.05;
 
-        // Advance to the starting iteration (skip iterations to maintain continuity)
         for _ in 0..start_iteration {
             let (xp, yp) = (x, y);
             if is_cubic {
@@ -2833,10 +5672,9 @@ impl FractalGenerator {
             }
         }
 
-        // Create density grid
+        let (cos_a, sin_a) = (angle.cos(), angle.sin());
         let mut density = vec![0u32; width * height];
 
-        // Generate the batch of points and directly add to density grid
         for _ in 0..n_points {
             let (xp, yp) = (x, y);
             if is_cubic {
@@ -2847,9 +5685,14 @@ impl FractalGenerator {
                 y = map_quadratic(y_params, xp, yp);
             }
 
-            // Add point to density grid
-            let pixel_x = ((x - min_x) / (max_x - min_x) * width as f64) as usize;
-            let pixel_y = ((y - min_y) / (max_y - min_y) * height as f64) as usize;
+            // Project into the principal-axis frame before binning.
+            let dx = x - mean_x;
+            let dy = y - mean_y;
+            let u = dx * cos_a + dy * sin_a;
+            let v = -dx * sin_a + dy * cos_a;
+
+            let pixel_x = ((u - min_u) / (max_u - min_u) * width as f64) as usize;
+            let pixel_y = ((v - min_v) / (max_v - min_v) * height as f64) as usize;
 
             if pixel_x < width && pixel_y < height {
                 density[pixel_y * width + pixel_x] += 1;
@@ -2950,6 +5793,97 @@ impl FractalGenerator {
 
         result
     }
+
+    /// Run a chaos game over a set of affine maps driven by a probabilistic finite automaton.
+    ///
+    /// `maps` is a flat list of affine transforms (six coefficients each, applied as
+    /// `x' = a·x + b·y + e`, `y' = c·x + d·y + f`). `transition` is a flat, row-major N×N
+    /// row-stochastic matrix over the N maps and `start_state` the initial map index: at each
+    /// step the next map is sampled from the current state's transition row, applied, and the
+    /// automaton moves to that state. This enforces grammar-like constraints plain uniform IFS
+    /// cannot (e.g. "map 3 can never follow map 1"). The return layout matches
+    /// [`Self::generate_chaotic_map_batch_with_state`]: final state, actual bounds, then the
+    /// density grid as `f64`.
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_automaton_ifs_density(
+        &mut self,
+        maps: &[f64],
+        transition: &[f64],
+        start_state: usize,
+        n_points: usize,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        start_x: f64,
+        start_y: f64,
+    ) -> Vec<f64> {
+        let n = maps.len() / 6;
+        let mut density = vec![0u32; width * height];
+
+        // Guard against a malformed matrix; fall back to uniform selection if it is not a
+        // valid N×N row-stochastic matrix.
+        let valid = n > 0
+            && transition.len() == n * n
+            && (0..n).all(|i| {
+                let row_sum: f64 = transition[i * n..(i + 1) * n].iter().sum();
+                (row_sum - 1.0).abs() < 1e-6
+            });
+        let uniform = vec![1.0 / n.max(1) as f64; n];
+
+        let mut x = start_x;
+        let mut y = start_y;
+        let mut state = if n > 0 { start_state.min(n - 1) } else { 0 };
+
+        let mut actual_min_x = x;
+        let mut actual_max_x = x;
+        let mut actual_min_y = y;
+        let mut actual_max_y = y;
+
+        for _ in 0..n_points {
+            let next = if valid {
+                self.select_transform(&transition[state * n..(state + 1) * n])
+            } else if n > 0 {
+                self.select_transform(&uniform)
+            } else {
+                break;
+            };
+            state = next;
+
+            let m = &maps[next * 6..next * 6 + 6];
+            let (xp, yp) = (x, y);
+            x = m[0] * xp + m[1] * yp + m[4];
+            y = m[2] * xp + m[3] * yp + m[5];
+
+            actual_min_x = actual_min_x.min(x);
+            actual_max_x = actual_max_x.max(x);
+            actual_min_y = actual_min_y.min(y);
+            actual_max_y = actual_max_y.max(y);
+
+            if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
+                let pixel_x = ((x - min_x) / (max_x - min_x) * width as f64) as usize;
+                let pixel_y = ((y - min_y) / (max_y - min_y) * height as f64) as usize;
+                if pixel_x < width && pixel_y < height {
+                    density[pixel_y * width + pixel_x] += 1;
+                }
+            }
+        }
+
+        let mut result = Vec::with_capacity(density.len() + 6);
+        result.push(x);
+        result.push(y);
+        result.push(actual_min_x);
+        result.push(actual_max_x);
+        result.push(actual_min_y);
+        result.push(actual_max_y);
+        for d in density {
+            result.push(d as f64);
+        }
+        result
+    }
 }
 
 /// Generate just the trajectory points for streaming processing
@@ -3029,3 +5963,710 @@ pub fn generate_chaotic_map_points(
 
     result
 }
+
+// -----------------------------------------------------------------------------
+// Optional GPU density-accumulation backend (wgpu / WebGPU)
+// -----------------------------------------------------------------------------
+
+/// Uniform block uploaded to the density compute shader. Layout must match the WGSL struct:
+/// scalars first, then the two coefficient vectors packed as `array<vec4<f32>, 3>` (10 of the
+/// 12 slots are used).
+#[cfg(feature = "gpu")]
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct GpuDensityParams {
+    width: u32,
+    height: u32,
+    is_cubic: u32,
+    iterations: u32,
+    threads: u32,
+    seed: u32,
+    _pad0: u32,
+    _pad1: u32,
+    min_x: f32,
+    max_x: f32,
+    min_y: f32,
+    max_y: f32,
+    x_params: [f32; 12],
+    y_params: [f32; 12],
+}
+
+/// WGSL compute shader mirroring `map_quadratic`/`map_cubic`: each invocation seeds its own
+/// orbit from a per-thread offset, iterates the map `iterations` times and `atomicAdd`s into a
+/// shared `u32` density buffer using the same affine transform as the CPU accumulator.
+#[cfg(feature = "gpu")]
+const DENSITY_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    is_cubic: u32,
+    iterations: u32,
+    threads: u32,
+    seed: u32,
+    pad0: u32,
+    pad1: u32,
+    min_x: f32,
+    max_x: f32,
+    min_y: f32,
+    max_y: f32,
+    xp: array<vec4<f32>, 3>,
+    yp: array<vec4<f32>, 3>,
+};
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> density: array<atomic<u32>>;
+
+fn map_quad(a: array<f32, 10>, x: f32, y: f32) -> f32 {
+    return a[0] + a[1] * x + a[2] * x * x + a[3] * x * y + a[4] * y + a[5] * y * y;
+}
+
+fn map_cub(a: array<f32, 10>, x: f32, y: f32) -> f32 {
+    return a[0] + a[1] * x + a[2] * x * x + a[3] * x * x * x + a[4] * x * x * y
+        + a[5] * x * y + a[6] * x * y * y + a[7] * y + a[8] * y * y + a[9] * y * y * y;
+}
+
+@compute @workgroup_size(64, 1, 1)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let tid = gid.x;
+    if (tid >= params.threads) {
+        return;
+    }
+
+    // Unpack the coefficient vectors into flat arrays.
+    var ax: array<f32, 10>;
+    var ay: array<f32, 10>;
+    for (var i: u32 = 0u; i < 10u; i = i + 1u) {
+        let v = params.xp[i / 4u];
+        let w = params.yp[i / 4u];
+        let c = i % 4u;
+        if (c == 0u) { ax[i] = v.x; ay[i] = w.x; }
+        else if (c == 1u) { ax[i] = v.y; ay[i] = w.y; }
+        else if (c == 2u) { ax[i] = v.z; ay[i] = w.z; }
+        else { ax[i] = v.w; ay[i] = w.w; }
+    }
+
+    // Seed this thread's orbit from a hashed per-thread offset in (-1, 1).
+    let h = (tid + params.seed) * 2654435761u;
+    var x: f32 = -1.0 + 2.0 * f32(h % 100003u) / 100003.0;
+    var y: f32 = -1.0 + 2.0 * f32((h / 100003u) % 100019u) / 100019.0;
+
+    for (var n: u32 = 0u; n < params.iterations; n = n + 1u) {
+        let xp = x;
+        let yp = y;
+        if (params.is_cubic == 1u) {
+            x = map_cub(ax, xp, yp);
+            y = map_cub(ay, xp, yp);
+        } else {
+            x = map_quad(ax, xp, yp);
+            y = map_quad(ay, xp, yp);
+        }
+
+        // Skip the initial transient before binning.
+        if (n < 20u) {
+            continue;
+        }
+        if (x >= params.min_x && x <= params.max_x && y >= params.min_y && y <= params.max_y) {
+            let px = u32((x - params.min_x) / (params.max_x - params.min_x) * f32(params.width));
+            let py = u32((y - params.min_y) / (params.max_y - params.min_y) * f32(params.height));
+            if (px < params.width && py < params.height) {
+                atomicAdd(&density[py * params.width + px], 1u);
+            }
+        }
+    }
+}
+"#;
+
+/// A cached wgpu device/queue that accumulates many independent orbits in parallel on the GPU.
+///
+/// Construction returns `None` when no adapter is available, letting [`ChaoticAccumulator::new_gpu`]
+/// fall back to the CPU `step_batch` path. The dense `u32` grid lives on the GPU and is copied
+/// back only once, at finalization.
+#[cfg(feature = "gpu")]
+pub struct GpuDensityBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+#[cfg(feature = "gpu")]
+impl GpuDensityBackend {
+    /// Try to acquire a GPU adapter, returning `None` if WebGPU is unavailable.
+    pub fn new() -> Option<Self> {
+        pollster::block_on(Self::new_async())
+    }
+
+    async fn new_async() -> Option<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .ok()?;
+        Some(GpuDensityBackend { device, queue })
+    }
+
+    /// Run `threads` orbits for `iterations` steps each and return the accumulated density grid.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate(
+        &self,
+        x_params: &[f64],
+        y_params: &[f64],
+        is_cubic: bool,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        threads: usize,
+        iterations: usize,
+    ) -> Vec<u32> {
+        pollster::block_on(self.accumulate_async(
+            x_params, y_params, is_cubic, width, height, min_x, max_x, min_y, max_y, threads,
+            iterations,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn accumulate_async(
+        &self,
+        x_params: &[f64],
+        y_params: &[f64],
+        is_cubic: bool,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        threads: usize,
+        iterations: usize,
+    ) -> Vec<u32> {
+        use wgpu::util::DeviceExt;
+
+        let pixels = width * height;
+        let buffer_size = (pixels * std::mem::size_of::<u32>()) as wgpu::BufferAddress;
+
+        let mut xp = [0.0f32; 12];
+        let mut yp = [0.0f32; 12];
+        for (i, &v) in x_params.iter().take(10).enumerate() {
+            xp[i] = v as f32;
+        }
+        for (i, &v) in y_params.iter().take(10).enumerate() {
+            yp[i] = v as f32;
+        }
+
+        let params = GpuDensityParams {
+            width: width as u32,
+            height: height as u32,
+            is_cubic: is_cubic as u32,
+            iterations: iterations as u32,
+            threads: threads as u32,
+            seed: 1u32,
+            _pad0: 0,
+            _pad1: 0,
+            min_x: min_x as f32,
+            max_x: max_x as f32,
+            min_y: min_y as f32,
+            max_y: max_y as f32,
+            x_params: xp,
+            y_params: yp,
+        };
+
+        let uniform = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("density-params"),
+                contents: bytemuck::bytes_of(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let storage = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("density-output"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("density-readback"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let shader = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("density-shader"),
+                source: wgpu::ShaderSource::Wgsl(DENSITY_SHADER.into()),
+            });
+
+        let pipeline = self
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("density-pipeline"),
+                layout: None,
+                module: &shader,
+                entry_point: "main",
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("density-bind-group"),
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: storage.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("density-pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let wg = ((threads as u32) + 63) / 64;
+            pass.dispatch_workgroups(wg, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&storage, 0, &readback, 0, buffer_size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = readback.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |res| {
+            let _ = tx.send(res);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        let _ = rx.recv();
+
+        let data = slice.get_mapped_range();
+        let result: Vec<u32> = bytemuck::cast_slice(&data).to_vec();
+        drop(data);
+        readback.unmap();
+        result
+    }
+}
+
+#[cfg(feature = "gpu")]
+#[wasm_bindgen]
+impl ChaoticAccumulator {
+    /// Build an accumulator whose density grid is populated on the GPU.
+    ///
+    /// Dispatches `threads` independent orbits of `iterations` steps each through the wgpu
+    /// [`GpuDensityBackend`]; the resulting `u32` counts are folded (saturating) into the dense
+    /// `u16` grid so the output feeds [`ChaoticAccumulator::to_rgba_log_soft`] identically to the
+    /// CPU path. When no adapter is present the GPU work is skipped and an empty CPU accumulator
+    /// is returned, ready for the usual `step_batch` loop.
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_gpu(
+        x_params: Vec<f64>,
+        y_params: Vec<f64>,
+        is_cubic: bool,
+        width: usize,
+        height: usize,
+        min_x: f64,
+        max_x: f64,
+        min_y: f64,
+        max_y: f64,
+        threads: usize,
+        iterations: usize,
+    ) -> ChaoticAccumulator {
+        let mut acc = ChaoticAccumulator::new(
+            x_params.clone(),
+            y_params.clone(),
+            is_cubic,
+            width,
+            height,
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            0.05,
+            0.05,
+        );
+
+        if let Some(backend) = GpuDensityBackend::new() {
+            let grid = backend.accumulate(
+                &x_params, &y_params, is_cubic, width, height, min_x, max_x, min_y, max_y, threads,
+                iterations,
+            );
+            let mut non_zero = 0usize;
+            for (i, &count) in grid.iter().enumerate() {
+                if count > 0 {
+                    non_zero += 1;
+                    acc.set_density(i, count.min(u16::MAX as u32) as u16);
+                }
+            }
+            acc.non_zero = non_zero;
+        }
+
+        acc
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Optional GPU escape-time backend (wgpu / WebGPU)
+// -----------------------------------------------------------------------------
+
+/// Uniform block uploaded to the escape-time compute shader. Layout must match the WGSL
+/// struct: the `u32` scalars first (padded to 16 bytes), then the `f32` viewport/iteration
+/// parameters. The viewport is described by its center and a single `zoom` factor so the same
+/// uniform drives native and browser renders from identical inputs.
+#[cfg(feature = "gpu")]
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct GpuEscapeParams {
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+    fractal_type: u32, // 0 = Mandelbrot, 1 = Julia
+    color_scheme: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    center_x: f32,
+    center_y: f32,
+    zoom: f32,
+    escape_radius: f32,
+    power: f32,
+    c_real: f32,
+    c_imag: f32,
+    _pad3: f32,
+}
+
+/// WGSL compute shader mirroring [`FractalGenerator::mandelbrot_set`]/[`julia_set`]: one
+/// invocation per pixel writing the (integer) escape iteration count into a storage buffer.
+/// The iteration `z ← z^power + c` is evaluated in polar form so a single kernel serves the
+/// generalized multibrot family. The kernel runs in `f32` and uses `pow`/`atan2`/`cos`/`sin`
+/// even at `power == 2`, so counts near the set boundary can differ from the `f64` CPU loop.
+#[cfg(feature = "gpu")]
+const ESCAPE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+    fractal_type: u32,
+    color_scheme: u32,
+    pad0: u32,
+    pad1: u32,
+    pad2: u32,
+    center_x: f32,
+    center_y: f32,
+    zoom: f32,
+    escape_radius: f32,
+    power: f32,
+    c_real: f32,
+    c_imag: f32,
+    pad3: f32,
+};
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> output: array<u32>;
+
+@compute @workgroup_size(8, 8, 1)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let px = gid.x;
+    let py = gid.y;
+    if (px >= params.width || py >= params.height) {
+        return;
+    }
+
+    // Map the pixel to the complex plane from center + zoom, preserving aspect ratio.
+    let aspect = f32(params.width) / f32(params.height);
+    let span_y = 1.0 / params.zoom;
+    let span_x = span_y * aspect;
+    let fx = params.center_x + (f32(px) / f32(params.width) - 0.5) * 2.0 * span_x;
+    let fy = params.center_y + (f32(py) / f32(params.height) - 0.5) * 2.0 * span_y;
+
+    var zx: f32;
+    var zy: f32;
+    var cx: f32;
+    var cy: f32;
+    if (params.fractal_type == 0u) {
+        zx = 0.0;
+        zy = 0.0;
+        cx = fx;
+        cy = fy;
+    } else {
+        zx = fx;
+        zy = fy;
+        cx = params.c_real;
+        cy = params.c_imag;
+    }
+
+    let er2 = params.escape_radius * params.escape_radius;
+    var iteration: u32 = 0u;
+    loop {
+        if (zx * zx + zy * zy > er2 || iteration >= params.max_iterations) {
+            break;
+        }
+        // z ← z^power + c via polar form (handles fractional powers).
+        let r = sqrt(zx * zx + zy * zy);
+        let theta = atan2(zy, zx);
+        let rp = pow(r, params.power);
+        let nt = theta * params.power;
+        zx = rp * cos(nt) + cx;
+        zy = rp * sin(nt) + cy;
+        iteration = iteration + 1u;
+    }
+
+    output[py * params.width + px] = iteration;
+}
+"#;
+
+/// A cached wgpu device/queue running the escape-time loop on the GPU.
+///
+/// Construction returns `None` when no adapter is available, letting callers fall back to the
+/// CPU [`FractalGenerator::mandelbrot_set`]/[`julia_set`] path. The returned `Vec<u32>` uses the
+/// same row-major iteration-count layout as those functions, so either backend is a drop-in for
+/// the other — natively or in the browser.
+#[cfg(feature = "gpu")]
+pub struct GpuEscapeBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+#[cfg(feature = "gpu")]
+impl GpuEscapeBackend {
+    /// Try to acquire a GPU adapter, returning `None` if WebGPU is unavailable.
+    pub fn new() -> Option<Self> {
+        pollster::block_on(Self::new_async())
+    }
+
+    async fn new_async() -> Option<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .ok()?;
+        Some(GpuEscapeBackend { device, queue })
+    }
+
+    /// Run the escape-time loop on the GPU and return per-pixel iteration counts.
+    #[allow(clippy::too_many_arguments)]
+    fn run(&self, params: GpuEscapeParams) -> Vec<u32> {
+        pollster::block_on(self.run_async(params))
+    }
+
+    async fn run_async(&self, params: GpuEscapeParams) -> Vec<u32> {
+        use wgpu::util::DeviceExt;
+
+        let width = params.width as usize;
+        let height = params.height as usize;
+        let pixels = width * height;
+        let buffer_size = (pixels * std::mem::size_of::<u32>()) as wgpu::BufferAddress;
+
+        let uniform = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("escape-params"),
+                contents: bytemuck::bytes_of(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let storage = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("escape-output"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("escape-readback"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let shader = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("escape-shader"),
+                source: wgpu::ShaderSource::Wgsl(ESCAPE_SHADER.into()),
+            });
+
+        let pipeline = self
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("escape-pipeline"),
+                layout: None,
+                module: &shader,
+                entry_point: "main",
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("escape-bind-group"),
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: storage.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("escape-pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let wg_x = ((width as u32) + 7) / 8;
+            let wg_y = ((height as u32) + 7) / 8;
+            pass.dispatch_workgroups(wg_x, wg_y, 1);
+        }
+        encoder.copy_buffer_to_buffer(&storage, 0, &readback, 0, buffer_size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = readback.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |res| {
+            let _ = tx.send(res);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        let _ = rx.recv();
+
+        let data = slice.get_mapped_range();
+        let result: Vec<u32> = bytemuck::cast_slice(&data).to_vec();
+        drop(data);
+        readback.unmap();
+        result
+    }
+}
+
+#[cfg(feature = "gpu")]
+#[wasm_bindgen]
+impl FractalGenerator {
+    /// Generate a Mandelbrot iteration grid on the GPU, falling back to the CPU
+    /// [`FractalGenerator::mandelbrot_set`] when no WebGPU adapter is present. The bounds are
+    /// converted to the center/zoom uniform, with the horizontal span derived from the vertical
+    /// span and `width/height`, so the pixel grid matches the CPU path only when
+    /// `(x_max − x_min)/(y_max − y_min)` equals `width/height`. The shader iterates in `f32`,
+    /// so counts near the set boundary can differ from the CPU result.
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn mandelbrot_set_gpu(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        max_iterations: usize,
+    ) -> Vec<u32> {
+        self.escape_set_gpu(
+            width, height, x_min, x_max, y_min, y_max, max_iterations, 0, 0.0, 0.0, 2.0, 2.0,
+        )
+        .unwrap_or_else(|| {
+            self.mandelbrot_set(width, height, x_min, x_max, y_min, y_max, max_iterations)
+        })
+    }
+
+    /// Generate a Julia iteration grid on the GPU, falling back to the CPU
+    /// [`FractalGenerator::julia_set`] when no WebGPU adapter is present.
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn julia_set_gpu(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        c_real: f64,
+        c_imag: f64,
+        max_iterations: usize,
+    ) -> Vec<u32> {
+        self.escape_set_gpu(
+            width, height, x_min, x_max, y_min, y_max, max_iterations, 1, c_real, c_imag, 2.0, 2.0,
+        )
+        .unwrap_or_else(|| {
+            self.julia_set(width, height, x_min, x_max, y_min, y_max, c_real, c_imag, max_iterations)
+        })
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl FractalGenerator {
+    /// Shared GPU escape-time dispatch. Converts the `[x_min, x_max] × [y_min, y_max]` bounds to
+    /// the center/zoom uniform the shader expects and returns `None` when no adapter is available.
+    #[allow(clippy::too_many_arguments)]
+    fn escape_set_gpu(
+        &self,
+        width: usize,
+        height: usize,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        max_iterations: usize,
+        fractal_type: u32,
+        c_real: f64,
+        c_imag: f64,
+        escape_radius: f64,
+        power: f64,
+    ) -> Option<Vec<u32>> {
+        let backend = GpuEscapeBackend::new()?;
+        let center_x = (x_min + x_max) * 0.5;
+        let center_y = (y_min + y_max) * 0.5;
+        // zoom is defined so that the vertical half-span equals 1/zoom.
+        let zoom = 2.0 / (y_max - y_min);
+        let params = GpuEscapeParams {
+            width: width as u32,
+            height: height as u32,
+            max_iterations: max_iterations as u32,
+            fractal_type,
+            color_scheme: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
+            center_x: center_x as f32,
+            center_y: center_y as f32,
+            zoom: zoom as f32,
+            escape_radius: escape_radius as f32,
+            power: power as f32,
+            c_real: c_real as f32,
+            c_imag: c_imag as f32,
+            _pad3: 0.0,
+        };
+        Some(backend.run(params))
+    }
+}
@@ -4,16 +4,50 @@
 mod fractals;
 
 use fractals::{
-    FractalGenerator, Point2D, Transform, AffineTransform, Rule, 
-    ColorScheme, ChaoticMapResult
+    FractalGenerator, Point2D, Transform, AffineTransform, Rule,
+    ColorScheme, ChaoticMapResult, Palette, AnimationSpec, GpuBackend, OrbitTrap, Scene
 };
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Mutex;
 use tauri::State;
 
+// Lazily-initialized GPU backend cache. The adapter is probed on first use and the
+// result (available or not) is remembered for the lifetime of the app.
+enum GpuCache {
+    Uninitialized,
+    Unavailable,
+    Ready(GpuBackend),
+}
+
+impl GpuCache {
+    /// Probe for a GPU adapter on first access, caching the outcome.
+    fn ensure(&mut self) -> &mut GpuCache {
+        if let GpuCache::Uninitialized = self {
+            *self = match GpuBackend::new() {
+                Some(backend) => GpuCache::Ready(backend),
+                None => GpuCache::Unavailable,
+            };
+        }
+        self
+    }
+}
+
 // Application state to maintain fractal generator instance
 struct AppState {
     generator: Mutex<FractalGenerator>,
+    palette_dir: PathBuf,
+    gpu: Mutex<GpuCache>,
+}
+
+impl AppState {
+    fn palette_path(&self, name: &str) -> Result<PathBuf, String> {
+        // Guard against path traversal; palettes live as flat files in the palette dir.
+        if name.is_empty() || name.contains(['/', '\\', '.']) {
+            return Err(format!("invalid palette name: {name}"));
+        }
+        Ok(self.palette_dir.join(format!("{name}.json")))
+    }
 }
 
 // Request/Response structures for Tauri commands
@@ -25,6 +59,8 @@ struct ChaosGameParams {
     iterations: usize,
     transforms: Vec<Transform>,
     rule: Rule,
+    #[serde(default)]
+    seed: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -33,6 +69,8 @@ struct IFSParams {
     iterations: usize,
     transforms: Vec<AffineTransform>,
     probabilities: Vec<f64>,
+    #[serde(default)]
+    seed: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -44,6 +82,14 @@ struct MandelbrotParams {
     x_max: f64,
     y_min: f64,
     y_max: f64,
+    #[serde(default = "default_samples")]
+    samples: usize,
+    #[serde(default)]
+    use_gpu: bool,
+}
+
+fn default_samples() -> usize {
+    1
 }
 
 #[derive(Serialize, Deserialize)]
@@ -57,6 +103,145 @@ struct JuliaParams {
     y_max: f64,
     c_real: f64,
     c_imag: f64,
+    #[serde(default = "default_samples")]
+    samples: usize,
+    #[serde(default)]
+    use_gpu: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MandelbrotDeepParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    center_re: String,
+    center_im: String,
+    radius: f64,
+    precision_bits: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MandelbulbStlParams {
+    resolution: usize,
+    power: f64,
+    max_iterations: usize,
+    min: f64,
+    max: f64,
+    isolevel: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MandelbrotTrapParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    trap: OrbitTrap,
+    scale: f64,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JuliaTrapParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    c_real: f64,
+    c_imag: f64,
+    trap: OrbitTrap,
+    scale: f64,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MultibrotParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    power: f64,
+    escape_radius: f64,
+    #[serde(default = "default_samples")]
+    samples: usize,
+    color_scheme: i32,
+    // Optional cyclic palette controls; `period <= 0` disables cycling.
+    #[serde(default)]
+    period: f64,
+    #[serde(default)]
+    color_offset: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MandelbrotDeepSmoothParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    center_re: String,
+    center_im: String,
+    radius: f64,
+    precision_bits: u32,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MandelbrotDeParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JuliaDeParams {
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    c_real: f64,
+    c_imag: f64,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BuddhabrotParams {
+    width: usize,
+    height: usize,
+    samples: usize,
+    max_iterations: usize,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    color_scheme: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NebulabrotParams {
+    width: usize,
+    height: usize,
+    samples: usize,
+    caps: [usize; 3],
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -83,6 +268,7 @@ async fn generate_chaos_game(
         params.iterations,
         params.transforms,
         &mut rule,
+        params.seed,
     ))
 }
 
@@ -99,6 +285,7 @@ async fn generate_ifs_fractal(
         params.iterations,
         params.transforms,
         params.probabilities,
+        params.seed,
     ))
 }
 
@@ -108,8 +295,25 @@ async fn generate_mandelbrot(
     state: State<'_, AppState>,
     params: MandelbrotParams,
 ) -> Result<Vec<u32>, String> {
+    // Prefer the GPU when requested and the canvas is large enough to amortise the
+    // upload/readback cost; otherwise fall back to the parallel CPU implementation.
+    if params.use_gpu && params.width * params.height >= GpuBackend::MIN_GPU_PIXELS {
+        let mut gpu = state.gpu.lock().map_err(|e| e.to_string())?;
+        if let GpuCache::Ready(backend) = gpu.ensure() {
+            return Ok(backend.mandelbrot_set(
+                params.width,
+                params.height,
+                params.max_iterations,
+                params.x_min,
+                params.x_max,
+                params.y_min,
+                params.y_max,
+            ));
+        }
+    }
+
     let generator = state.generator.lock().map_err(|e| e.to_string())?;
-    
+
     Ok(generator.mandelbrot_set(
         params.width,
         params.height,
@@ -118,6 +322,7 @@ async fn generate_mandelbrot(
         params.x_max,
         params.y_min,
         params.y_max,
+        params.samples,
     ))
 }
 
@@ -127,8 +332,25 @@ async fn generate_julia(
     state: State<'_, AppState>,
     params: JuliaParams,
 ) -> Result<Vec<u32>, String> {
+    if params.use_gpu && params.width * params.height >= GpuBackend::MIN_GPU_PIXELS {
+        let mut gpu = state.gpu.lock().map_err(|e| e.to_string())?;
+        if let GpuCache::Ready(backend) = gpu.ensure() {
+            return Ok(backend.julia_set(
+                params.width,
+                params.height,
+                params.max_iterations,
+                params.x_min,
+                params.x_max,
+                params.y_min,
+                params.y_max,
+                params.c_real,
+                params.c_imag,
+            ));
+        }
+    }
+
     let generator = state.generator.lock().map_err(|e| e.to_string())?;
-    
+
     Ok(generator.julia_set(
         params.width,
         params.height,
@@ -139,6 +361,244 @@ async fn generate_julia(
         params.y_max,
         params.c_real,
         params.c_imag,
+        params.samples,
+    ))
+}
+
+// Tauri command for generating a deep-zoom Mandelbrot set via perturbation theory
+#[tauri::command]
+async fn generate_mandelbrot_deep(
+    state: State<'_, AppState>,
+    params: MandelbrotDeepParams,
+) -> Result<Vec<u32>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+
+    Ok(generator.mandelbrot_set_deep(
+        params.width,
+        params.height,
+        params.max_iterations,
+        &params.center_re,
+        &params.center_im,
+        params.radius,
+        params.precision_bits,
+    ))
+}
+
+// Tauri command for meshing a Mandelbulb isosurface to binary STL
+#[tauri::command]
+async fn generate_mandelbulb_stl(
+    state: State<'_, AppState>,
+    params: MandelbulbStlParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let field = generator.mandelbulb_field(
+        params.resolution,
+        params.power,
+        params.max_iterations,
+        params.min,
+        params.max,
+    );
+    let triangles = generator.marching_cubes(
+        &field,
+        params.resolution,
+        params.min,
+        params.max,
+        params.isolevel,
+    );
+    Ok(generator.mesh_to_binary_stl(&triangles))
+}
+
+// Tauri command for orbit-trap coloring of the Mandelbrot set
+#[tauri::command]
+async fn generate_mandelbrot_trap(
+    state: State<'_, AppState>,
+    params: MandelbrotTrapParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.mandelbrot_set_trap(
+        params.width,
+        params.height,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+        params.trap,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.trap_values_to_rgba(&values, params.width, params.height, params.scale, color_scheme))
+}
+
+// Tauri command for orbit-trap coloring of a Julia set
+#[tauri::command]
+async fn generate_julia_trap(
+    state: State<'_, AppState>,
+    params: JuliaTrapParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.julia_set_trap(
+        params.width,
+        params.height,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+        params.c_real,
+        params.c_imag,
+        params.trap,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.trap_values_to_rgba(&values, params.width, params.height, params.scale, color_scheme))
+}
+
+// Tauri command for rendering a generalized multibrot set
+#[tauri::command]
+async fn generate_multibrot(
+    state: State<'_, AppState>,
+    params: MultibrotParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.multibrot_set_smooth(
+        params.width,
+        params.height,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+        params.power,
+        params.escape_radius,
+        params.samples,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    let image = if params.period > 0.0 {
+        generator.smooth_values_to_rgba_cyclic(
+            &values,
+            params.width,
+            params.height,
+            params.max_iterations,
+            params.period,
+            params.color_offset,
+            color_scheme,
+        )
+    } else {
+        generator.smooth_values_to_rgba(
+            &values,
+            params.width,
+            params.height,
+            params.max_iterations,
+            color_scheme,
+        )
+    };
+    Ok(image)
+}
+
+// Tauri command for smooth-colored deep-zoom Mandelbrot rendering
+#[tauri::command]
+async fn generate_mandelbrot_deep_smooth(
+    state: State<'_, AppState>,
+    params: MandelbrotDeepSmoothParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.mandelbrot_set_deep_smooth(
+        params.width,
+        params.height,
+        params.max_iterations,
+        &params.center_re,
+        &params.center_im,
+        params.radius,
+        params.precision_bits,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.smooth_values_to_rgba(
+        &values,
+        params.width,
+        params.height,
+        params.max_iterations,
+        color_scheme,
+    ))
+}
+
+// Tauri command for distance-estimate rendering of the Mandelbrot set
+#[tauri::command]
+async fn generate_mandelbrot_de(
+    state: State<'_, AppState>,
+    params: MandelbrotDeParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.mandelbrot_set_de(
+        params.width,
+        params.height,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.de_values_to_rgba(&values, params.width, params.height, color_scheme))
+}
+
+// Tauri command for distance-estimate rendering of a Julia set
+#[tauri::command]
+async fn generate_julia_de(
+    state: State<'_, AppState>,
+    params: JuliaDeParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let values = generator.julia_set_de(
+        params.width,
+        params.height,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+        params.c_real,
+        params.c_imag,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.de_values_to_rgba(&values, params.width, params.height, color_scheme))
+}
+
+// Tauri command for rendering a Buddhabrot density image
+#[tauri::command]
+async fn generate_buddhabrot(
+    state: State<'_, AppState>,
+    params: BuddhabrotParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    let histogram = generator.buddhabrot(
+        params.width,
+        params.height,
+        params.samples,
+        params.max_iterations,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
+    );
+    let color_scheme = ColorScheme::from(params.color_scheme);
+    Ok(generator.buddhabrot_to_rgba(&histogram, params.width, params.height, color_scheme))
+}
+
+// Tauri command for rendering a three-channel Nebulabrot image
+#[tauri::command]
+async fn generate_nebulabrot(
+    state: State<'_, AppState>,
+    params: NebulabrotParams,
+) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    Ok(generator.nebulabrot(
+        params.width,
+        params.height,
+        params.samples,
+        params.caps,
+        params.x_min,
+        params.x_max,
+        params.y_min,
+        params.y_max,
     ))
 }
 
@@ -159,18 +619,85 @@ async fn points_to_rgba(
     ))
 }
 
+// Tauri command to persist a custom palette to the palette directory
+#[tauri::command]
+async fn save_palette(state: State<'_, AppState>, palette: Palette) -> Result<(), String> {
+    std::fs::create_dir_all(&state.palette_dir).map_err(|e| e.to_string())?;
+    let path = state.palette_path(&palette.name)?;
+    let json = serde_json::to_string_pretty(&palette).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+// Tauri command to load a previously saved palette by name
+#[tauri::command]
+async fn load_palette(state: State<'_, AppState>, name: String) -> Result<Palette, String> {
+    let path = state.palette_path(&name)?;
+    let json = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+// Tauri command to list the names of all saved palettes
+#[tauri::command]
+async fn list_palettes(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let mut names = Vec::new();
+    let entries = match std::fs::read_dir(&state.palette_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(names), // directory not created yet -> no palettes
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                names.push(stem.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+// Tauri command to render a declarative scene document to an RGBA buffer
+#[tauri::command]
+async fn render_scene(state: State<'_, AppState>, scene: Scene) -> Result<Vec<u8>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    Ok(generator.render_scene(&scene))
+}
+
+// Tauri command to parse a scene document and render it in one step
+#[tauri::command]
+async fn render_scene_json(state: State<'_, AppState>, json: String) -> Result<Vec<u8>, String> {
+    let scene = FractalGenerator::load_scene(&json).map_err(|e| e.to_string())?;
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    Ok(generator.render_scene(&scene))
+}
+
+// Tauri command to render an interpolated animation sequence to RGBA frame buffers
+#[tauri::command]
+async fn render_animation(
+    state: State<'_, AppState>,
+    spec: AnimationSpec,
+) -> Result<Vec<Vec<u8>>, String> {
+    let generator = state.generator.lock().map_err(|e| e.to_string())?;
+    Ok(generator.render_animation(&spec))
+}
+
 // Tauri command to get system capabilities for desktop optimization
 #[tauri::command]
-async fn get_system_info() -> Result<serde_json::Value, String> {
+async fn get_system_info(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let gpu_available = {
+        let mut gpu = state.gpu.lock().map_err(|e| e.to_string())?;
+        matches!(gpu.ensure(), GpuCache::Ready(_))
+    };
     let info = serde_json::json!({
         "max_canvas_size": 16384, // Much higher than browser limit
-        "max_iterations": 100_000_000, // Much higher than browser limit  
+        "max_iterations": 100_000_000, // Much higher than browser limit
         "parallel_processing": true,
         "available_cores": num_cpus::get(),
         "platform": std::env::consts::OS,
         "architecture": std::env::consts::ARCH,
+        "gpu_available": gpu_available,
     });
-    
+
     Ok(info)
 }
 
@@ -178,13 +705,31 @@ fn main() {
     tauri::Builder::default()
         .manage(AppState {
             generator: Mutex::new(FractalGenerator::new()),
+            palette_dir: std::env::temp_dir().join("fractal-generator").join("palettes"),
+            gpu: Mutex::new(GpuCache::Uninitialized),
         })
         .invoke_handler(tauri::generate_handler![
             generate_chaos_game,
             generate_ifs_fractal,
             generate_mandelbrot,
             generate_julia,
+            generate_mandelbrot_deep,
+            generate_mandelbrot_deep_smooth,
+            generate_multibrot,
+            generate_mandelbulb_stl,
+            generate_mandelbrot_trap,
+            generate_julia_trap,
+            generate_mandelbrot_de,
+            generate_julia_de,
+            generate_buddhabrot,
+            generate_nebulabrot,
             points_to_rgba,
+            save_palette,
+            load_palette,
+            list_palettes,
+            render_scene,
+            render_scene_json,
+            render_animation,
             get_system_info
         ])
         .run(tauri::generate_context!())
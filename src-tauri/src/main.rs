@@ -28,7 +28,7 @@ fn main() {
     let mut rule = Rule::new(0, 0, false);
     
     let start_time = std::time::Instant::now();
-    let points = generator.chaos_game(vertices, 0.5, 0.25, 1_000_000, transforms, &mut rule);
+    let points = generator.chaos_game(vertices, 0.5, 0.25, 1_000_000, transforms, &mut rule, None);
     let duration = start_time.elapsed();
     
     println!("✅ Generated {} points in {:.2}ms", points.len(), duration.as_millis());
@@ -46,7 +46,7 @@ fn main() {
     let probabilities = vec![0.01, 0.85, 0.07, 0.07];
     
     let start_time = std::time::Instant::now();
-    let points = generator.ifs_fractal(Point2D::new(0.0, 0.0), 500_000, transforms, probabilities);
+    let points = generator.ifs_fractal(Point2D::new(0.0, 0.0), 500_000, transforms, probabilities, None);
     let duration = start_time.elapsed();
     
     println!("✅ Generated {} points in {:.2}ms", points.len(), duration.as_millis());
@@ -56,7 +56,7 @@ fn main() {
     // Demo 3: High-resolution Mandelbrot Set
     println!("🌀 Generating Mandelbrot Set (2048x2048, 1000 iterations)...");
     let start_time = std::time::Instant::now();
-    let result = generator.mandelbrot_set(2048, 2048, 1000, -2.5, 1.0, -1.25, 1.25);
+    let result = generator.mandelbrot_set(2048, 2048, 1000, -2.5, 1.0, -1.25, 1.25, 1);
     let duration = start_time.elapsed();
     
     println!("✅ Generated {}x{} Mandelbrot in {:.2}ms", 2048, 2048, duration.as_millis());
@@ -116,7 +116,7 @@ mod tests {
         let mut rule = Rule::new(0, 0, false);
         
         let start = std::time::Instant::now();
-        let points = generator.chaos_game(vertices, 0.5, 0.25, 10_000, transforms, &mut rule);
+        let points = generator.chaos_game(vertices, 0.5, 0.25, 10_000, transforms, &mut rule, None);
         let duration = start.elapsed();
         
         assert_eq!(points.len(), 10_000);
@@ -128,7 +128,7 @@ mod tests {
         let generator = FractalGenerator::new();
         
         let start = std::time::Instant::now();
-        let result = generator.mandelbrot_set(256, 256, 100, -2.0, 1.0, -1.0, 1.0);
+        let result = generator.mandelbrot_set(256, 256, 100, -2.0, 1.0, -1.0, 1.0, 1);
         let duration = start.elapsed();
         
         assert_eq!(result.len(), 256 * 256);